@@ -0,0 +1,186 @@
+//! Blocking versions of the WorkOS operations, enabled with the `blocking` feature.
+//!
+//! Each module of this module re-exports the types of the corresponding API module together with a
+//! blocking version of each of its operation traits. Importing the blocking traits instead of the
+//! asynchronous ones makes the operations of a [`WorkOs`](crate::WorkOs) client synchronous.
+//!
+//! The operations are run to completion on a runtime that is shared by all clients and created on
+//! first use, so no asynchronous runtime needs to be set up.
+//!
+//! # Panics
+//!
+//! The blocking operations panic when called from within an asynchronous runtime. Use the
+//! asynchronous operations there instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use workos::blocking::organizations::*;
+//! use workos::{ApiKey, WorkOs, WorkOsResult};
+//!
+//! # fn run() -> WorkOsResult<(), GetOrganizationError> {
+//! let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+//!
+//! let organization = workos
+//!     .organizations()
+//!     .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::{Builder, Runtime};
+
+/// Defines a blocking version of each of the listed operation traits of an API module, and
+/// implements it for the API of the module by blocking on the asynchronous operation.
+macro_rules! blocking_operations {
+    (
+        $module:ident :: $api:ident {
+            $( $operation:ident :: $method:ident ( $( $param:ident : $param_ty:ty ),* ) -> $output:ty; )*
+        }
+    ) => {
+        $(
+            #[doc = concat!(
+                "The blocking version of [`", stringify!($operation), "`](crate::",
+                stringify!($module), "::", stringify!($operation), ")."
+            )]
+            pub trait $operation {
+                #[doc = concat!(
+                    "The blocking version of [`", stringify!($operation), "::", stringify!($method),
+                    "`](crate::", stringify!($module), "::", stringify!($operation), "::",
+                    stringify!($method), ")."
+                )]
+                fn $method(&self, $( $param: $param_ty ),*) -> $output;
+            }
+
+            impl $operation for $api<'_> {
+                fn $method(&self, $( $param: $param_ty ),*) -> $output {
+                    $crate::blocking::block_on(
+                        <Self as $crate::$module::$operation>::$method(self, $( $param ),*),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+pub mod directory_sync;
+pub mod events;
+pub mod mfa;
+pub mod organization_domains;
+pub mod organizations;
+pub mod portal;
+pub mod roles;
+pub mod sso;
+pub mod user_management;
+pub mod widgets;
+
+/// Runs the future to completion on the shared runtime of the blocking operations.
+fn block_on<F: Future>(future: F) -> F::Output {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+    RUNTIME
+        .get_or_init(|| {
+            Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("the runtime of the blocking operations should build")
+        })
+        .block_on(future)
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod test {
+    use mockito::Matcher;
+    use serde_json::json;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::organizations::*;
+    use super::user_management::{CreateUser, CreateUserParams, UserId};
+
+    #[test]
+    fn it_calls_operations_synchronously() {
+        let mut server = mockito::Server::new();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded("order".to_string(), "desc".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                  "data": [
+                    {
+                      "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                      "object": "organization",
+                      "name": "Foo Corp",
+                      "allow_profiles_outside_organization": false,
+                      "created_at": "2021-06-25T19:07:33.155Z",
+                      "updated_at": "2021-06-25T19:07:33.155Z",
+                      "domains": []
+                    }
+                  ],
+                  "list_metadata": {
+                    "before": null,
+                    "after": null
+                  }
+                })
+                .to_string(),
+            )
+            .create();
+
+        server
+            .mock("POST", "/user_management/users")
+            .with_status(201)
+            .with_body(
+                json!({
+                    "object": "user",
+                    "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "email": "marcelina.davis@example.com",
+                    "first_name": "Marcelina",
+                    "last_name": "Davis",
+                    "email_verified": false,
+                    "profile_picture_url": null,
+                    "metadata": {},
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create();
+
+        let organizations = workos
+            .organizations()
+            .list_organizations(&Default::default())
+            .unwrap();
+
+        assert_eq!(
+            organizations.data.into_iter().next().map(|org| org.id),
+            Some(OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+        );
+
+        let user = workos
+            .user_management()
+            .create_user(&CreateUserParams {
+                email: "marcelina.davis@example.com",
+                password: None,
+                first_name: None,
+                last_name: None,
+                email_verified: None,
+                external_id: None,
+                metadata: None,
+                idempotency_key: None,
+            })
+            .unwrap();
+
+        assert_eq!(user.id, UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"))
+    }
+}
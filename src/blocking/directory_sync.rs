@@ -0,0 +1,17 @@
+//! Blocking operations of the WorkOS Directory Sync API.
+
+pub use crate::directory_sync::*;
+
+use crate::{PaginatedList, WorkOsResult};
+
+blocking_operations! {
+    directory_sync::DirectorySync {
+        DeleteDirectory::delete_directory(directory_id: &DirectoryId) -> WorkOsResult<(), DeleteDirectoryError>;
+        GetDirectory::get_directory(id: &DirectoryId) -> WorkOsResult<Directory, GetDirectoryError>;
+        GetDirectoryGroup::get_directory_group(id: &DirectoryGroupId) -> WorkOsResult<DirectoryGroup, GetDirectoryGroupError>;
+        GetDirectoryUser::get_directory_user(id: &DirectoryUserId) -> WorkOsResult<DirectoryUser, GetDirectoryUserError>;
+        ListDirectories::list_directories(params: &ListDirectoriesParams<'_>) -> WorkOsResult<PaginatedList<Directory>, ()>;
+        ListDirectoryGroups::list_directory_groups(params: &ListDirectoryGroupsParams<'_>) -> WorkOsResult<PaginatedList<DirectoryGroup>, ()>;
+        ListDirectoryUsers::list_directory_users(params: &ListDirectoryUsersParams<'_>) -> WorkOsResult<PaginatedList<DirectoryUser>, ()>;
+    }
+}
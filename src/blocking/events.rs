@@ -0,0 +1,11 @@
+//! Blocking operations of the WorkOS Events API.
+
+pub use crate::events::*;
+
+use crate::{PaginatedList, WorkOsResult};
+
+blocking_operations! {
+    events::Events {
+        ListEvents::list_events(params: &ListEventsParams<'_>) -> WorkOsResult<PaginatedList<Event>, ()>;
+    }
+}
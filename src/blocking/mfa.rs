@@ -0,0 +1,15 @@
+//! Blocking operations of the WorkOS Multi-Factor Authentication API.
+
+pub use crate::mfa::*;
+
+use crate::WorkOsResult;
+
+blocking_operations! {
+    mfa::Mfa {
+        ChallengeFactor::challenge_factor(params: &ChallengeFactorParams<'_>) -> WorkOsResult<AuthenticationChallenge, ChallengeFactorError>;
+        DeleteFactor::delete_factor(organization_id: &AuthenticationFactorId) -> WorkOsResult<(), DeleteFactorError>;
+        EnrollFactor::enroll_factor(params: &EnrollFactorParams<'_>) -> WorkOsResult<AuthenticationFactor, EnrollFactorError>;
+        GetFactor::get_factor(id: &AuthenticationFactorId) -> WorkOsResult<AuthenticationFactor, GetFactorError>;
+        VerifyChallenge::verify_challenge(params: &VerifyChallengeParams<'_>) -> WorkOsResult<VerifyChallengeResponse, VerifyChallengeError>;
+    }
+}
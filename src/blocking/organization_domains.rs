@@ -0,0 +1,14 @@
+//! Blocking operations of the WorkOS Organization Domains API.
+
+pub use crate::organization_domains::*;
+
+use crate::WorkOsResult;
+
+blocking_operations! {
+    organization_domains::OrganizationDomains {
+        CreateOrganizationDomain::create_organization_domain(params: &CreateOrganizationDomainParams<'_>) -> WorkOsResult<OrganizationDomain, CreateOrganizationDomainError>;
+        DeleteOrganizationDomain::delete_organization_domain(organization_domain_id: &OrganizationDomainId) -> WorkOsResult<(), DeleteOrganizationDomainError>;
+        GetOrganizationDomain::get_organization_domain(id: &OrganizationDomainId) -> WorkOsResult<OrganizationDomain, GetOrganizationDomainError>;
+        VerifyOrganizationDomain::verify_organization_domain(organization_domain_id: &OrganizationDomainId) -> WorkOsResult<OrganizationDomain, VerifyOrganizationDomainError>;
+    }
+}
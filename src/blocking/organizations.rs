@@ -0,0 +1,16 @@
+//! Blocking operations of the WorkOS Organizations API.
+
+pub use crate::organizations::*;
+
+use crate::{PaginatedList, WorkOsResult};
+
+blocking_operations! {
+    organizations::Organizations {
+        CreateOrganization::create_organization(params: &CreateOrganizationParams<'_>) -> WorkOsResult<Organization, CreateOrganizationError>;
+        DeleteOrganization::delete_organization(organization_id: &OrganizationId) -> WorkOsResult<(), DeleteOrganizationError>;
+        GetOrganization::get_organization(id: &OrganizationId) -> WorkOsResult<Organization, GetOrganizationError>;
+        GetOrganizationByExternalId::get_organization_by_external_id(external_id: &str) -> WorkOsResult<Organization, GetOrganizationByExternalIdError>;
+        ListOrganizations::list_organizations(params: &ListOrganizationsParams<'_>) -> WorkOsResult<PaginatedList<Organization>, ()>;
+        UpdateOrganization::update_organization(params: &UpdateOrganizationParams<'_>) -> WorkOsResult<Organization, UpdateOrganizationError>;
+    }
+}
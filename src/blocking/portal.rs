@@ -0,0 +1,11 @@
+//! Blocking operations of the WorkOS Admin Portal API.
+
+pub use crate::portal::*;
+
+use crate::WorkOsResult;
+
+blocking_operations! {
+    portal::Portal {
+        GeneratePortalLink::generate_portal_link(params: &GeneratePortalLinkParams<'_>) -> WorkOsResult<GeneratePortalLinkResponse, GeneratePortalLinkError>;
+    }
+}
@@ -0,0 +1,11 @@
+//! Blocking operations of the WorkOS Roles API.
+
+pub use crate::roles::*;
+
+use crate::{UnpaginatedList, WorkOsResult};
+
+blocking_operations! {
+    roles::Roles {
+        ListOrganizationRoles::list_organization_roles(params: &ListOrganizationRolesParams) -> WorkOsResult<UnpaginatedList<Role>, ListOrganizationRolesError>;
+    }
+}
@@ -0,0 +1,15 @@
+//! Blocking operations of the WorkOS Single Sign-On API.
+
+pub use crate::sso::*;
+
+use crate::{PaginatedList, WorkOsResult};
+
+blocking_operations! {
+    sso::Sso {
+        DeleteConnection::delete_connection(connection_id: &ConnectionId) -> WorkOsResult<(), DeleteConnectionError>;
+        GetConnection::get_connection(id: &ConnectionId) -> WorkOsResult<Connection, GetConnectionError>;
+        GetProfile::get_profile(access_token: &AccessToken) -> WorkOsResult<Profile, GetProfileError>;
+        GetProfileAndToken::get_profile_and_token(params: &GetProfileAndTokenParams<'_>) -> WorkOsResult<GetProfileAndTokenResponse, GetProfileAndTokenError>;
+        ListConnections::list_connections(params: &ListConnectionsParams<'_>) -> WorkOsResult<PaginatedList<Connection>, ()>;
+    }
+}
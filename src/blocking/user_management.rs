@@ -0,0 +1,51 @@
+//! Blocking operations of the WorkOS User Management API.
+
+pub use crate::user_management::*;
+
+use jsonwebtoken::jwk::JwkSet;
+
+use crate::mfa::AuthenticationFactor;
+use crate::sso::ClientId;
+use crate::{PaginatedList, WorkOsResult};
+
+blocking_operations! {
+    user_management::UserManagement {
+        AcceptInvitation::accept_invitation(invitation_id: &InvitationId) -> WorkOsResult<Invitation, AcceptInvitationError>;
+        AuthenticateWithCode::authenticate_with_code(params: &AuthenticateWithCodeParams<'_>) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
+        AuthenticateWithDeviceCode::authenticate_with_device_code(params: &AuthenticateWithDeviceCodeParams<'_>) -> WorkOsResult<AuthenticationResponse, AuthenticateWithDeviceCodeError>;
+        AuthenticateWithEmailVerification::authenticate_with_email_verification(params: &AuthenticateWithEmailVerificationParams<'_>) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
+        AuthenticateWithMagicAuth::authenticate_with_magic_auth(params: &AuthenticateWithMagicAuthParams<'_>) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
+        AuthenticateWithPassword::authenticate_with_password(params: &AuthenticateWithPasswordParams<'_>) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
+        AuthenticateWithRefreshToken::authenticate_with_refresh_token(params: &AuthenticateWithRefreshTokenParams<'_>) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
+        AuthenticateWithTotp::authenticate_with_totp(params: &AuthenticateWithTotpParams<'_>) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
+        CreateMagicAuth::create_magic_auth(params: &CreateMagicAuthParams<'_>) -> WorkOsResult<MagicAuth, CreateMagicAuthError>;
+        CreateOrganizationMembership::create_organization_membership(params: &CreateOrganizationMembershipParams<'_>) -> WorkOsResult<OrganizationMembership, CreateOrganizationMembershipError>;
+        CreatePasswordReset::create_password_reset(params: &CreatePasswordResetParams<'_>) -> WorkOsResult<PasswordReset, CreatePasswordResetError>;
+        CreateUser::create_user(params: &CreateUserParams<'_>) -> WorkOsResult<User, CreateUserError>;
+        DeactivateOrganizationMembership::deactivate_organization_membership(organization_membership_id: &OrganizationMembershipId) -> WorkOsResult<OrganizationMembership, DeactivateOrganizationMembershipError>;
+        DeleteOrganizationMembership::delete_organization_membership(organization_membership_id: &OrganizationMembershipId) -> WorkOsResult<(), DeleteOrganizationMembershipError>;
+        DeleteUser::delete_user(user_id: &UserId) -> WorkOsResult<(), DeleteUserError>;
+        EnrollAuthFactor::enroll_auth_factor(params: &EnrollAuthFactorParams<'_>) -> WorkOsResult<EnrollAuthFactorResponse, EnrollAuthFactorError>;
+        FindInvitationByToken::find_invitation_by_token(token: &InvitationToken) -> WorkOsResult<Invitation, FindInvitationByTokenError>;
+        GetDeviceAuthorizationUrl::get_device_authorization_url(params: &GetDeviceAuthorizationUrlParams<'_>) -> WorkOsResult<GetDeviceAuthorizationUrlResponse, GetDeviceAuthorizationUrlError>;
+        GetEmailVerification::get_email_verification(id: &EmailVerificationId) -> WorkOsResult<EmailVerification, GetEmailVerificationError>;
+        GetInvitation::get_invitation(id: &InvitationId) -> WorkOsResult<Invitation, GetInvitationError>;
+        GetJwks::get_jwks(client_id: Option<&ClientId>) -> WorkOsResult<JwkSet, GetJwksError>;
+        GetMagicAuth::get_magic_auth(id: &MagicAuthId) -> WorkOsResult<MagicAuth, GetMagicAuthError>;
+        GetOrganizationMembership::get_organization_membership(id: &OrganizationMembershipId) -> WorkOsResult<OrganizationMembership, GetOrganizationMembershipError>;
+        GetPasswordReset::get_password_reset(id: &PasswordResetId) -> WorkOsResult<PasswordReset, GetPasswordResetError>;
+        GetUser::get_user(id: &UserId) -> WorkOsResult<User, GetUserError>;
+        GetUserByExternalId::get_user_by_external_id(external_id: &str) -> WorkOsResult<User, GetUserByExternalIdError>;
+        GetUserIdentities::get_user_identities(user_id: &UserId) -> WorkOsResult<Vec<Identity>, GetUserIdentitiesError>;
+        ListAuthFactors::list_auth_factors(params: &ListAuthFactorsParams<'_>) -> WorkOsResult<PaginatedList<AuthenticationFactor>, ()>;
+        ListInvitations::list_invitations(params: &ListInvitationsParams) -> WorkOsResult<PaginatedList<Invitation>, ListInvitationsError>;
+        ListOrganizationMemberships::list_organization_memberships(params: &ListOrganizationMembershipsParams) -> WorkOsResult<PaginatedList<OrganizationMembership>, ListOrganizationMembershipsError>;
+        ListUsers::list_users(params: &ListUsersParams<'_>) -> WorkOsResult<PaginatedList<User>, ListUsersError>;
+        ReactivateOrganizationMembership::reactivate_organization_membership(organization_membership_id: &OrganizationMembershipId) -> WorkOsResult<OrganizationMembership, ReactivateOrganizationMembershipError>;
+        ResetPassword::reset_password(params: &ResetPasswordParams<'_>) -> WorkOsResult<ResetPasswordResponse, ResetPasswordError>;
+        RevokeInvitation::revoke_invitation(invitation_id: &InvitationId) -> WorkOsResult<Invitation, RevokeInvitationError>;
+        SendInvitation::send_invitation(params: &SendInvitationParams<'_>) -> WorkOsResult<Invitation, SendInvitationError>;
+        UpdateOrganizationMembership::update_organization_membership(params: &UpdateOrganizationMembershipParams<'_>) -> WorkOsResult<OrganizationMembership, UpdateOrganizationMembershipError>;
+        UpdateUser::update_user(params: &UpdateUserParams<'_>) -> WorkOsResult<User, UpdateUserError>;
+    }
+}
@@ -0,0 +1,11 @@
+//! Blocking operations of the WorkOS Widgets API.
+
+pub use crate::widgets::*;
+
+use crate::WorkOsResult;
+
+blocking_operations! {
+    widgets::Widgets {
+        GenerateToken::generate_token(params: &GenerateTokenParams<'_>) -> WorkOsResult<GenerateTokenResponse, GenerateTokenError>;
+    }
+}
@@ -1,7 +1,23 @@
+mod client;
+mod credentials;
 mod error;
+mod middleware;
+mod paginator;
 mod response;
+mod response_meta;
+mod retry;
+mod time;
+mod transport;
 mod types;
 
+pub(crate) use client::*;
+pub use credentials::*;
 pub use error::*;
+pub use middleware::*;
+pub use paginator::*;
 pub(crate) use response::*;
+pub use response_meta::*;
+pub use retry::*;
+pub(crate) use time::*;
+pub use transport::*;
 pub use types::*;
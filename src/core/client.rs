@@ -0,0 +1,573 @@
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::header::{CONTENT_TYPE, HeaderValue, USER_AGENT};
+use http::{HeaderMap, Method};
+use serde::Serialize;
+use url::Url;
+
+use crate::{
+    HttpRequest, HttpResponse, HttpTransport, Instant, Middleware, Next, RequestError, RetryPolicy,
+    TransportError, capture_response_meta, sleep,
+};
+
+/// The HTTP client used to send requests to the WorkOS API.
+#[derive(Clone)]
+pub(crate) struct Client {
+    transport: Arc<dyn HttpTransport>,
+    middleware: Arc<[Arc<dyn Middleware>]>,
+    retry_policy: RetryPolicy,
+    timeout: Option<Duration>,
+    headers: HeaderMap,
+}
+
+impl Client {
+    pub(crate) fn new(
+        transport: Arc<dyn HttpTransport>,
+        middleware: Vec<Arc<dyn Middleware>>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            transport,
+            middleware: middleware.into(),
+            retry_policy,
+            timeout: None,
+            headers: HeaderMap::new(),
+        }
+    }
+
+    /// Returns a copy of the client that applies the given timeout and additional headers to
+    /// every request.
+    pub(crate) fn with_options(
+        &self,
+        timeout: Option<Duration>,
+        headers: Option<&HeaderMap>,
+    ) -> Self {
+        let mut client = self.clone();
+
+        if let Some(timeout) = timeout {
+            client.timeout = Some(timeout);
+        }
+
+        if let Some(headers) = headers {
+            override_headers(&mut client.headers, headers);
+        }
+
+        client
+    }
+
+    pub(crate) fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Sends a single attempt of the request through the middleware chain and the transport.
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        Next::new(&self.middleware, self.transport.as_ref())
+            .run(request)
+            .await
+    }
+
+    async fn execute_with_retries(
+        &self,
+        request: HttpRequest,
+    ) -> Result<HttpResponse, TransportError> {
+        let policy = self.retry_policy();
+
+        let retryable = policy.is_retryable_request(&request.method, &request.headers);
+        let mut attempt = 1;
+
+        loop {
+            if !retryable || attempt >= policy.max_attempts {
+                return self.execute(request).await;
+            }
+
+            let delay = match self.execute(request.clone()).await {
+                Ok(response) if policy.is_retryable_status(response.status) => {
+                    match policy.delay(attempt, Some(&response.headers)) {
+                        Some(delay) => delay,
+                        None => return Ok(response),
+                    }
+                }
+                Err(TransportError::Connect(_) | TransportError::Timeout(_)) => policy
+                    .delay(attempt, None)
+                    .expect("delay should be available without headers"),
+                result => return result,
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "retrying request to the WorkOS API"
+            );
+
+            sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    pub(crate) fn request(&self, method: Method, url: Url) -> RequestBuilder<'_> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_static(concat!("workos-rust/", env!("CARGO_PKG_VERSION"))),
+        );
+        override_headers(&mut headers, &self.headers);
+
+        RequestBuilder {
+            client: self,
+            request: Ok(HttpRequest {
+                method,
+                url,
+                headers,
+                body: None,
+                timeout: self.timeout,
+            }),
+        }
+    }
+
+    pub(crate) fn get(&self, url: Url) -> RequestBuilder<'_> {
+        self.request(Method::GET, url)
+    }
+
+    pub(crate) fn post(&self, url: Url) -> RequestBuilder<'_> {
+        self.request(Method::POST, url)
+    }
+
+    pub(crate) fn put(&self, url: Url) -> RequestBuilder<'_> {
+        self.request(Method::PUT, url)
+    }
+
+    pub(crate) fn delete(&self, url: Url) -> RequestBuilder<'_> {
+        self.request(Method::DELETE, url)
+    }
+}
+
+/// A builder for a request to the WorkOS API.
+///
+/// Errors that occur while building the request are returned when it is sent.
+pub(crate) struct RequestBuilder<'a> {
+    client: &'a Client,
+    request: Result<HttpRequest, RequestError>,
+}
+
+impl RequestBuilder<'_> {
+    fn with_request<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut HttpRequest) -> Result<(), RequestError>,
+    {
+        if let Ok(request) = &mut self.request
+            && let Err(err) = f(request)
+        {
+            self.request = Err(err);
+        }
+
+        self
+    }
+
+    pub(crate) fn query<T: Serialize + ?Sized>(self, query: &T) -> Self {
+        self.with_request(|request| {
+            let result = {
+                let mut pairs = request.url.query_pairs_mut();
+
+                query
+                    .serialize(serde_urlencoded::Serializer::new(&mut pairs))
+                    .map(|_| ())
+            };
+
+            if request.url.query() == Some("") {
+                request.url.set_query(None);
+            }
+
+            result.map_err(|err| RequestError::Build(Box::new(err)))
+        })
+    }
+
+    pub(crate) fn json<T: Serialize + ?Sized>(self, json: &T) -> Self {
+        self.with_request(|request| {
+            let body =
+                serde_json::to_vec(json).map_err(|err| RequestError::Build(Box::new(err)))?;
+
+            request
+                .headers
+                .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            request.body = Some(body);
+
+            Ok(())
+        })
+    }
+
+    pub(crate) fn form<T: Serialize + ?Sized>(self, form: &T) -> Self {
+        self.with_request(|request| {
+            let body = serde_urlencoded::to_string(form)
+                .map_err(|err| RequestError::Build(Box::new(err)))?;
+
+            request.headers.insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/x-www-form-urlencoded"),
+            );
+            request.body = Some(body.into_bytes());
+
+            Ok(())
+        })
+    }
+
+    pub(crate) fn bearer_auth<T: Display>(self, token: T) -> Self {
+        self.with_request(|request| {
+            let mut value = HeaderValue::try_from(format!("Bearer {token}"))
+                .map_err(|err| RequestError::Build(Box::new(err)))?;
+            value.set_sensitive(true);
+
+            request.headers.insert(http::header::AUTHORIZATION, value);
+
+            Ok(())
+        })
+    }
+
+    pub(crate) fn header(self, key: &'static str, value: &str) -> Self {
+        self.with_request(|request| {
+            let value =
+                HeaderValue::try_from(value).map_err(|err| RequestError::Build(Box::new(err)))?;
+
+            request.headers.insert(key, value);
+
+            Ok(())
+        })
+    }
+
+    /// Sets the `Idempotency-Key` header, which also allows the request to be retried.
+    pub(crate) fn idempotency_key(self, idempotency_key: Option<&str>) -> Self {
+        match idempotency_key {
+            Some(idempotency_key) => self.header("Idempotency-Key", idempotency_key),
+            None => self,
+        }
+    }
+
+    /// Sends the request, retrying it according to the [`RetryPolicy`] of the client.
+    pub(crate) async fn send(self) -> Result<HttpResponse, RequestError> {
+        let request = self.request?;
+
+        let start = Instant::now();
+
+        let result = self.client.execute_with_retries(request).await;
+        let elapsed = start.elapsed();
+
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => {
+                #[cfg(feature = "tracing")]
+                record_transport_error(&err, elapsed);
+
+                return Err(err.into());
+            }
+        };
+
+        capture_response_meta(&response, elapsed);
+
+        #[cfg(feature = "tracing")]
+        record_response(&response, elapsed);
+
+        Ok(response)
+    }
+}
+
+/// Sets the given headers, replacing the existing values of headers with the same name.
+fn override_headers(target: &mut HeaderMap, headers: &HeaderMap) {
+    for name in headers.keys() {
+        target.remove(name);
+    }
+
+    for (name, value) in headers {
+        target.append(name.clone(), value.clone());
+    }
+}
+
+/// Records the outcome of a request on the span of the current operation.
+#[cfg(feature = "tracing")]
+fn record_response(response: &HttpResponse, latency: std::time::Duration) {
+    let span = tracing::Span::current();
+
+    span.record("http.response.status_code", response.status.as_u16());
+    span.record("latency_ms", latency.as_millis() as u64);
+
+    if let Some(request_id) = response
+        .headers
+        .get(crate::REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+    {
+        span.record("workos.request_id", request_id);
+    }
+}
+
+/// Records a request that failed without a response on the span of the current operation.
+#[cfg(feature = "tracing")]
+fn record_transport_error(error: &TransportError, latency: std::time::Duration) {
+    let span = tracing::Span::current();
+
+    span.record("latency_ms", latency.as_millis() as u64);
+    span.record(
+        "error.type",
+        match error {
+            TransportError::Connect(_) => "connect",
+            TransportError::Timeout(_) => "timeout",
+            TransportError::Other(_) => "transport",
+        },
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use http::StatusCode;
+    use serde_json::json;
+
+    use super::*;
+
+    /// A transport that returns the scripted responses in order and records the requests.
+    #[derive(Default)]
+    struct StubTransport {
+        responses: Mutex<VecDeque<HttpResponse>>,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl StubTransport {
+        fn new(
+            responses: impl IntoIterator<Item = (StatusCode, &'static [(&'static str, &'static str)])>,
+        ) -> Arc<Self> {
+            Arc::new(Self {
+                responses: Mutex::new(
+                    responses
+                        .into_iter()
+                        .map(|(status, headers)| HttpResponse {
+                            status,
+                            headers: headers
+                                .iter()
+                                .map(|(name, value)| {
+                                    (
+                                        http::HeaderName::from_static(name),
+                                        HeaderValue::from_static(value),
+                                    )
+                                })
+                                .collect(),
+                            body: Vec::new(),
+                        })
+                        .collect(),
+                ),
+                requests: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl HttpTransport for StubTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+            self.requests.lock().unwrap().push(request);
+
+            Ok(self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("the stub transport should have a response left"))
+        }
+    }
+
+    fn client(transport: Arc<dyn HttpTransport>) -> Client {
+        Client::new(
+            transport,
+            Vec::new(),
+            RetryPolicy {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(10),
+                jitter: false,
+                respect_retry_after: true,
+            },
+        )
+    }
+
+    fn url() -> Url {
+        Url::parse("https://api.workos.test").unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_overrides_existing_headers_with_the_per_call_headers() {
+        let transport = StubTransport::new([(StatusCode::OK, &[][..])]);
+
+        let mut first = HeaderMap::new();
+        first.insert(USER_AGENT, HeaderValue::from_static("first/1.0"));
+        first.insert("x-tenant", HeaderValue::from_static("foo"));
+
+        let mut second = HeaderMap::new();
+        second.insert(USER_AGENT, HeaderValue::from_static("second/1.0"));
+
+        client(transport.clone())
+            .with_options(None, Some(&first))
+            .with_options(None, Some(&second))
+            .get(url())
+            .send()
+            .await
+            .unwrap();
+
+        let requests = transport.requests.lock().unwrap();
+        let headers = &requests[0].headers;
+
+        assert_eq!(
+            headers.get_all(USER_AGENT).iter().collect::<Vec<_>>(),
+            vec![&HeaderValue::from_static("second/1.0")]
+        );
+        assert_eq!(
+            headers.get("x-tenant"),
+            Some(&HeaderValue::from_static("foo"))
+        )
+    }
+
+    #[tokio::test]
+    async fn it_retries_idempotent_requests_until_the_max_attempts() {
+        let transport = StubTransport::new([(StatusCode::BAD_GATEWAY, &[][..]); 3]);
+
+        let response = client(transport.clone()).get(url()).send().await.unwrap();
+
+        assert_eq!(response.status, StatusCode::BAD_GATEWAY);
+        assert_eq!(transport.requests(), 3)
+    }
+
+    #[tokio::test]
+    async fn it_stops_retrying_after_a_successful_response() {
+        let transport = StubTransport::new([
+            (StatusCode::TOO_MANY_REQUESTS, &[("retry-after", "0")][..]),
+            (StatusCode::OK, &[][..]),
+        ]);
+
+        let response = client(transport.clone()).get(url()).send().await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(transport.requests(), 2)
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_non_idempotent_requests() {
+        let transport = StubTransport::new([(StatusCode::SERVICE_UNAVAILABLE, &[][..])]);
+
+        let response = client(transport.clone())
+            .post(url())
+            .json(&json!({}))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(transport.requests(), 1)
+    }
+
+    #[tokio::test]
+    async fn it_retries_requests_with_an_idempotency_key() {
+        let transport = StubTransport::new([(StatusCode::SERVICE_UNAVAILABLE, &[][..]); 3]);
+
+        let response = client(transport.clone())
+            .post(url())
+            .json(&json!({}))
+            .idempotency_key(Some("key"))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(transport.requests(), 3);
+        assert!(transport.requests.lock().unwrap().iter().all(|request| {
+            request.headers.get("Idempotency-Key") == Some(&HeaderValue::from_static("key"))
+        }))
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_when_retry_after_exceeds_the_max_delay() {
+        let transport =
+            StubTransport::new([(StatusCode::TOO_MANY_REQUESTS, &[("retry-after", "60")][..])]);
+
+        let response = client(transport.clone()).get(url()).send().await.unwrap();
+
+        assert_eq!(response.status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(transport.requests(), 1)
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn it_records_transport_errors_on_the_span() {
+        use tracing::Instrument;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Id, Record};
+        use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+        struct TimeoutTransport;
+
+        #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+        #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+        impl HttpTransport for TimeoutTransport {
+            async fn send(&self, _request: HttpRequest) -> Result<HttpResponse, TransportError> {
+                Err(TransportError::Timeout("timed out".into()))
+            }
+        }
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for Capture {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("{}={value:?}", field.name()));
+            }
+        }
+
+        impl<S: tracing::Subscriber> Layer<S> for Capture {
+            fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+                values.record(&mut self.clone());
+            }
+        }
+
+        let capture = Capture::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        let span = tracing::info_span!(
+            "get_organization",
+            latency_ms = tracing::field::Empty,
+            error.type = tracing::field::Empty,
+        );
+
+        let result = client(Arc::new(TimeoutTransport))
+            .get(url())
+            .send()
+            .instrument(span)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(RequestError::Transport(TransportError::Timeout(_)))
+        ));
+
+        let records = capture.0.lock().unwrap();
+
+        assert!(
+            records
+                .iter()
+                .any(|record| record == "error.type=\"timeout\"")
+        );
+        assert!(
+            records
+                .iter()
+                .any(|record| record.starts_with("latency_ms="))
+        )
+    }
+}
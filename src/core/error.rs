@@ -1,5 +1,12 @@
+use std::fmt;
+use std::time::Duration;
+
+use http::StatusCode;
+use serde::{Deserialize, Deserializer};
 use thiserror::Error;
 
+use crate::{HttpResponse, REQUEST_ID_HEADER, RequestError};
+
 /// A JSON or text body.
 #[derive(Clone, Debug)]
 pub enum JsonOrText {
@@ -10,6 +17,139 @@ pub enum JsonOrText {
     Text(String),
 }
 
+/// An error response returned from the WorkOS API.
+#[derive(Clone, Debug, Error)]
+pub struct ApiError {
+    /// The response status code.
+    pub status: StatusCode,
+
+    /// A string constant that distinguishes the error type, if present.
+    pub code: Option<String>,
+
+    /// A human-readable message describing the error, if present.
+    pub message: Option<String>,
+
+    /// The field-level validation errors.
+    pub errors: Vec<ApiFieldError>,
+
+    /// The ID of the request, taken from the `X-Request-ID` response header.
+    pub request_id: Option<String>,
+
+    /// The raw response body.
+    pub body: JsonOrText,
+}
+
+impl ApiError {
+    /// Parses the error from a response of the WorkOS API.
+    pub(crate) fn from_response(response: &HttpResponse) -> Self {
+        #[derive(Default, Deserialize)]
+        struct ApiErrorBody {
+            code: Option<String>,
+
+            message: Option<String>,
+
+            /// The error code of OAuth-style error responses, used when there is no `code`.
+            error: Option<String>,
+
+            /// The error message of OAuth-style error responses, used when there is no `message`.
+            error_description: Option<String>,
+
+            #[serde(default, deserialize_with = "deserialize_field_errors")]
+            errors: Vec<ApiFieldError>,
+        }
+
+        /// Deserializes the field-level errors, skipping the ones that do not match the expected
+        /// shape, so that a single malformed entry does not discard the rest of the body.
+        fn deserialize_field_errors<'de, D>(deserializer: D) -> Result<Vec<ApiFieldError>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let errors = match serde_json::Value::deserialize(deserializer)? {
+                serde_json::Value::Array(errors) => errors
+                    .into_iter()
+                    .filter_map(|error| ApiFieldError::deserialize(error).ok())
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            Ok(errors)
+        }
+
+        let request_id = response
+            .headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+
+        let (parsed, body) = match serde_json::from_slice::<serde_json::Value>(&response.body) {
+            Ok(json) => (
+                ApiErrorBody::deserialize(&json).unwrap_or_default(),
+                JsonOrText::Json(json),
+            ),
+            Err(_) => (ApiErrorBody::default(), JsonOrText::Text(response.text())),
+        };
+
+        Self {
+            status: response.status,
+            code: parsed.code.or(parsed.error),
+            message: parsed.message.or(parsed.error_description),
+            errors: parsed.errors,
+            request_id,
+            body,
+        }
+    }
+}
+
+impl ApiError {
+    /// Returns the message for the given error code, if it is the code of the error or of one
+    /// of its field-level errors.
+    pub(crate) fn message_for_code(&self, code: &str) -> Option<String> {
+        if self.code.as_deref() == Some(code) {
+            return Some(self.message.clone().unwrap_or_default());
+        }
+
+        self.errors
+            .iter()
+            .find(|error| error.code.as_deref() == Some(code))
+            .map(|error| {
+                error
+                    .message
+                    .clone()
+                    .or_else(|| self.message.clone())
+                    .unwrap_or_default()
+            })
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.status)?;
+
+        if let Some(code) = &self.code {
+            write!(f, " {code}")?;
+        }
+
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A field-level validation error returned from the WorkOS API.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct ApiFieldError {
+    /// The field the error applies to, if present.
+    pub field: Option<String>,
+
+    /// A string constant that distinguishes the error type, if present.
+    pub code: Option<String>,
+
+    /// A human-readable message describing the error, if present.
+    pub message: Option<String>,
+}
+
 /// A WorkOS SDK error.
 #[derive(Debug, Error)]
 pub enum WorkOsError<E> {
@@ -21,16 +161,38 @@ pub enum WorkOsError<E> {
     #[error("unauthorized")]
     Unauthorized,
 
-    /// An unknown error response was received from the WorkOS API.
-    #[error("unknown error")]
-    Unknown {
-        /// The response status code.
-        status: reqwest::StatusCode,
+    /// A not found response was received from the WorkOS API.
+    #[error("not found: {0}")]
+    NotFound(Box<ApiError>),
+
+    /// A conflict response was received from the WorkOS API.
+    #[error("conflict: {0}")]
+    Conflict(Box<ApiError>),
+
+    /// An unprocessable entity response was received from the WorkOS API.
+    ///
+    /// This usually indicates that the request failed validation, see [`ApiError::errors`].
+    #[error("unprocessable entity: {0}")]
+    UnprocessableEntity(Box<ApiError>),
+
+    /// A rate limited response was received from the WorkOS API.
+    #[error("rate limited: {error}")]
+    RateLimited {
+        /// The delay after which the request may be retried, taken from the `Retry-After` response header.
+        retry_after: Option<Duration>,
 
-        /// The response body.
-        body: JsonOrText,
+        /// The error returned from the WorkOS API.
+        error: Box<ApiError>,
     },
 
+    /// An unknown error response was received from the WorkOS API.
+    #[error("unknown error: {0}")]
+    Unknown(Box<ApiError>),
+
+    /// No client ID was provided and the client has no default client ID.
+    #[error("missing client ID")]
+    MissingClientId,
+
     /// An error occurred while parsing a URL.
     #[error("URL parse error")]
     UrlParseError(#[from] url::ParseError),
@@ -41,7 +203,28 @@ pub enum WorkOsError<E> {
 
     /// An unhandled error occurred with the API request.
     #[error("request error")]
-    RequestError(#[from] reqwest::Error),
+    RequestError(#[from] RequestError),
+}
+
+impl<E> From<BuildUrlError> for WorkOsError<E> {
+    fn from(err: BuildUrlError) -> Self {
+        match err {
+            BuildUrlError::MissingClientId => Self::MissingClientId,
+            BuildUrlError::UrlParseError(err) => Self::UrlParseError(err),
+        }
+    }
+}
+
+/// An error returned from operations that build a URL.
+#[derive(Debug, Error)]
+pub enum BuildUrlError {
+    /// No client ID was provided and the client has no default client ID.
+    #[error("missing client ID")]
+    MissingClientId,
+
+    /// An error occurred while parsing a URL.
+    #[error("URL parse error")]
+    UrlParseError(#[from] url::ParseError),
 }
 
 /// A WorkOS SDK result.
@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{HttpRequest, HttpResponse, HttpTransport, TransportError};
+
+/// A middleware that sees every request sent to the WorkOS API and every response received from it.
+///
+/// Middleware is invoked in the order in which it was added to the [`WorkOsBuilder`](crate::WorkOsBuilder),
+/// once for every attempt of a request. Each middleware decides whether to continue the chain by calling
+/// [`Next::run`], which allows it to modify the request, inspect or modify the response, or
+/// short-circuit the request entirely.
+///
+/// On `wasm32` targets the returned future is not required to be [`Send`], so implementations use
+/// `#[async_trait(?Send)]` there.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Instant;
+///
+/// use async_trait::async_trait;
+/// use workos::{ApiKey, HttpRequest, HttpResponse, Middleware, Next, TransportError, WorkOs};
+///
+/// struct Latency;
+///
+/// #[async_trait]
+/// impl Middleware for Latency {
+///     async fn handle(
+///         &self,
+///         request: HttpRequest,
+///         next: Next<'_>,
+///     ) -> Result<HttpResponse, TransportError> {
+///         let start = Instant::now();
+///         let path = request.url.path().to_owned();
+///
+///         let response = next.run(request).await;
+///
+///         println!("{path} took {:?}", start.elapsed());
+///
+///         response
+///     }
+/// }
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .middleware(Latency)
+///     .build()
+///     .unwrap();
+/// ```
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Middleware: Send + Sync {
+    /// Handles the request, calling [`Next::run`] to pass it on to the rest of the chain.
+    async fn handle(
+        &self,
+        request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, TransportError>;
+}
+
+/// The remainder of a middleware chain, ending with the [`HttpTransport`].
+pub struct Next<'a> {
+    middleware: &'a [Arc<dyn Middleware>],
+    transport: &'a dyn HttpTransport,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        middleware: &'a [Arc<dyn Middleware>],
+        transport: &'a dyn HttpTransport,
+    ) -> Self {
+        Self {
+            middleware,
+            transport,
+        }
+    }
+
+    /// Passes the request on to the next middleware, or to the transport if this is the end of the chain.
+    pub async fn run(self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => {
+                middleware
+                    .handle(request, Next::new(rest, self.transport))
+                    .await
+            }
+            None => self.transport.send(request).await,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod test {
+    use std::sync::Mutex;
+
+    use http::StatusCode;
+    use http::header::HeaderValue;
+    use url::Url;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    struct AddHeader(&'static str, &'static str);
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl Middleware for AddHeader {
+        async fn handle(
+            &self,
+            mut request: HttpRequest,
+            next: Next<'_>,
+        ) -> Result<HttpResponse, TransportError> {
+            request
+                .headers
+                .insert(self.0, HeaderValue::from_static(self.1));
+
+            next.run(request).await
+        }
+    }
+
+    struct Record(&'static str, Arc<Mutex<Vec<String>>>);
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl Middleware for Record {
+        async fn handle(
+            &self,
+            request: HttpRequest,
+            next: Next<'_>,
+        ) -> Result<HttpResponse, TransportError> {
+            self.1.lock().unwrap().push(format!("{} request", self.0));
+
+            let response = next.run(request).await?;
+
+            self.1.lock().unwrap().push(format!(
+                "{} response {}",
+                self.0,
+                response.status.as_u16()
+            ));
+
+            Ok(response)
+        }
+    }
+
+    #[tokio::test]
+    async fn it_runs_middleware_in_order() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/health")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .middleware(Record("first", log.clone()))
+            .middleware(Record("second", log.clone()))
+            .build()
+            .unwrap();
+
+        let url = Url::parse(&server.url()).unwrap().join("/health").unwrap();
+        workos.client().get(url).send().await.unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "first request",
+                "second request",
+                "second response 200",
+                "first response 200"
+            ]
+        )
+    }
+
+    #[tokio::test]
+    async fn it_allows_middleware_to_modify_the_request() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/health")
+            .match_header("X-Custom-Header", "custom")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .middleware(AddHeader("X-Custom-Header", "custom"))
+            .build()
+            .unwrap();
+
+        let url = Url::parse(&server.url()).unwrap().join("/health").unwrap();
+        let response = workos.client().get(url).send().await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK)
+    }
+
+    #[tokio::test]
+    async fn it_allows_middleware_to_short_circuit_the_request() {
+        struct Fault;
+
+        #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+        #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+        impl Middleware for Fault {
+            async fn handle(
+                &self,
+                _request: HttpRequest,
+                _next: Next<'_>,
+            ) -> Result<HttpResponse, TransportError> {
+                Err(TransportError::Other("injected fault".into()))
+            }
+        }
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .middleware(Fault)
+            .build()
+            .unwrap();
+
+        let url = Url::parse("https://api.workos.com/health").unwrap();
+        let result = workos.client().get(url).send().await;
+
+        assert!(matches!(
+            result,
+            Err(crate::RequestError::Transport(TransportError::Other(_)))
+        ))
+    }
+}
@@ -0,0 +1,786 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{PaginatedList, PaginationOrder, PaginationParams, WorkOsResult};
+
+/// The direction in which a [`Paginator`] walks through the pages of a list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaginationDirection {
+    /// Pages are retrieved using the `after` cursor of the previous page.
+    Forward,
+
+    /// Pages are retrieved using the `before` cursor of the previous page.
+    Backward,
+}
+
+/// The pagination parameters of a page requested by a [`Paginator`].
+#[derive(Clone, Debug)]
+pub struct PageRequest {
+    /// The order in which records should be paginated.
+    pub order: PaginationOrder,
+
+    /// The cursor after which records should be retrieved.
+    pub after: Option<String>,
+
+    /// The cursor before which records should be retrieved.
+    pub before: Option<String>,
+
+    /// Upper limit on the number of objects to return.
+    pub limit: Option<u8>,
+}
+
+impl PageRequest {
+    /// Returns the [`PaginationParams`] to pass to the list operation.
+    pub fn pagination_params(&self) -> PaginationParams<'_> {
+        PaginationParams {
+            order: &self.order,
+            after: self.after.as_deref(),
+            before: self.before.as_deref(),
+            limit: self.limit,
+        }
+    }
+}
+
+/// The list operation and filter parameters that a [`Paginator`] walks through.
+///
+/// The scope is recorded in the [`PaginationCursor`] of the paginator, so that the cursor can only
+/// be resumed against the same list.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaginationScope {
+    /// The name of the list operation, such as `list_users`.
+    pub operation: String,
+
+    /// The parameters of the list operation, serialized as JSON.
+    pub params: serde_json::Value,
+}
+
+impl PaginationScope {
+    /// Returns a new [`PaginationScope`] for the given list operation and parameters.
+    ///
+    /// The parameters should not change while paginating, so the pagination parameters of the
+    /// operation should be left to their defaults.
+    pub fn new(operation: &str, params: &impl Serialize) -> serde_json::Result<Self> {
+        Ok(Self {
+            operation: operation.to_string(),
+            params: serde_json::to_value(params)?,
+        })
+    }
+}
+
+/// The position of a [`Paginator`], which can be persisted to resume the pagination later.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaginationCursor {
+    /// The list operation and filter parameters the cursor belongs to.
+    pub scope: PaginationScope,
+
+    /// The order in which records are paginated.
+    pub order: PaginationOrder,
+
+    /// The direction in which the pages are walked through.
+    pub direction: PaginationDirection,
+
+    /// The cursor used to retrieve the page containing the next item.
+    pub cursor: Option<String>,
+
+    /// The number of items of that page that were already yielded.
+    pub offset: usize,
+
+    /// The number of items to request per page.
+    pub page_size: Option<u8>,
+
+    /// The number of items that remain to be yielded under the item cap.
+    pub remaining: Option<usize>,
+}
+
+/// An error returned from [`Paginator::resume`].
+#[derive(Debug, Error)]
+pub enum ResumePaginationError {
+    /// The cursor was created by a paginator over a different list operation.
+    #[error("pagination cursor belongs to `{cursor}`, not `{expected}`")]
+    OperationMismatch {
+        /// The operation of the paginator being resumed.
+        expected: String,
+
+        /// The operation recorded in the cursor.
+        cursor: String,
+    },
+
+    /// The cursor was created by a paginator with different filter parameters.
+    #[error("pagination cursor was created with different parameters")]
+    ParamsMismatch,
+}
+
+/// A [`Stream`] over the items of a paginated list operation, which retrieves pages as needed.
+///
+/// The stream ends after the last page, once the item cap is reached or after yielding an error.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::WorkOsResult;
+/// # use workos::user_management::*;
+/// use futures_util::TryStreamExt;
+/// use workos::{ApiKey, PaginationParams, Paginator, WorkOs};
+///
+/// # async fn run() -> WorkOsResult<(), ListUsersError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+/// let user_management = &workos.user_management();
+///
+/// let mut users = Paginator::new(&PaginationParams::default(), |page| async move {
+///     user_management
+///         .list_users(&ListUsersParams {
+///             pagination: page.pagination_params(),
+///             ..Default::default()
+///         })
+///         .await
+/// })
+/// .page_size(100)
+/// .max_items(1000);
+///
+/// while let Some(user) = users.try_next().await? {
+///     println!("{}", user.email);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginator<T, F, Fut> {
+    fetch: F,
+    scope: Option<PaginationScope>,
+    order: PaginationOrder,
+    direction: PaginationDirection,
+    cursor: Option<String>,
+    page_size: Option<u8>,
+    max_items: Option<usize>,
+    yielded: usize,
+    buffer: VecDeque<T>,
+    page_cursor: Option<String>,
+    page_offset: usize,
+    skip: usize,
+    pending: Option<Pin<Box<Fut>>>,
+    finished: bool,
+}
+
+impl<T, E, F, Fut> Paginator<T, F, Fut>
+where
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = WorkOsResult<PaginatedList<T>, E>>,
+{
+    /// Returns a new [`Paginator`] starting at the given pagination parameters.
+    ///
+    /// The paginator walks backward if a `before` cursor is given and forward otherwise,
+    /// and uses the `limit` as the page size.
+    pub fn new(params: &PaginationParams<'_>, fetch: F) -> Self {
+        let (direction, cursor) = match (params.after, params.before) {
+            (None, Some(before)) => (PaginationDirection::Backward, Some(before)),
+            (after, _) => (PaginationDirection::Forward, after),
+        };
+
+        Self {
+            fetch,
+            scope: None,
+            order: *params.order,
+            direction,
+            cursor: cursor.map(String::from),
+            page_size: params.limit,
+            max_items: None,
+            yielded: 0,
+            buffer: VecDeque::new(),
+            page_cursor: None,
+            page_offset: 0,
+            skip: 0,
+            pending: None,
+            finished: false,
+        }
+    }
+
+    /// Returns a new [`Paginator`] resuming at the position of the given [`PaginationCursor`].
+    ///
+    /// Returns an error if the cursor was created for a different [`PaginationScope`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::WorkOsResult;
+    /// # use workos::directory_sync::*;
+    /// use futures_util::TryStreamExt;
+    /// use workos::{ApiKey, PaginationCursor, PaginationScope, Paginator, WorkOs};
+    ///
+    /// # async fn run(saved_cursor: &str) -> WorkOsResult<(), ()> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    /// let directory_sync = &workos.directory_sync();
+    /// let directory = &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74");
+    ///
+    /// let scope = PaginationScope::new(
+    ///     "list_directory_users",
+    ///     &ListDirectoryUsersParams {
+    ///         pagination: Default::default(),
+    ///         directory: Some(directory),
+    ///         group: None,
+    ///     },
+    /// )
+    /// .unwrap();
+    ///
+    /// let cursor: Option<PaginationCursor> = serde_json::from_str(saved_cursor).unwrap();
+    /// # let cursor = cursor.unwrap();
+    ///
+    /// let mut users = Paginator::resume(&cursor, &scope, |page| async move {
+    ///     directory_sync
+    ///         .list_directory_users(&ListDirectoryUsersParams {
+    ///             pagination: page.pagination_params(),
+    ///             directory: Some(directory),
+    ///             group: None,
+    ///         })
+    ///         .await
+    /// })
+    /// .unwrap();
+    ///
+    /// while let Some(user) = users.try_next().await? {
+    ///     println!("{}", user.id);
+    ///
+    ///     // Persist the position to resume from it after a restart.
+    ///     let saved_cursor = serde_json::to_string(&users.cursor()).unwrap();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resume(
+        cursor: &PaginationCursor,
+        scope: &PaginationScope,
+        fetch: F,
+    ) -> Result<Self, ResumePaginationError> {
+        if cursor.scope.operation != scope.operation {
+            return Err(ResumePaginationError::OperationMismatch {
+                expected: scope.operation.clone(),
+                cursor: cursor.scope.operation.clone(),
+            });
+        }
+
+        if cursor.scope.params != scope.params {
+            return Err(ResumePaginationError::ParamsMismatch);
+        }
+
+        Ok(Self {
+            fetch,
+            scope: Some(scope.clone()),
+            order: cursor.order,
+            direction: cursor.direction,
+            cursor: cursor.cursor.clone(),
+            page_size: cursor.page_size,
+            max_items: cursor.remaining,
+            yielded: 0,
+            buffer: VecDeque::new(),
+            page_cursor: None,
+            page_offset: 0,
+            skip: cursor.offset,
+            pending: None,
+            finished: false,
+        })
+    }
+
+    /// Returns the current position of the paginator, or [`None`] if it has ended or has no
+    /// [`PaginationScope`].
+    ///
+    /// Resuming at this position with [`Paginator::resume`] yields the items that were not
+    /// yielded yet, including those already retrieved.
+    pub fn cursor(&self) -> Option<PaginationCursor> {
+        let scope = self.scope.clone()?;
+        let remaining = self.remaining();
+
+        let (cursor, offset) = if !self.buffer.is_empty() {
+            (self.page_cursor.clone(), self.page_offset)
+        } else if self.finished || remaining == Some(0) {
+            return None;
+        } else {
+            (self.cursor.clone(), self.skip)
+        };
+
+        Some(PaginationCursor {
+            scope,
+            order: self.order,
+            direction: self.direction,
+            cursor,
+            offset,
+            page_size: self.page_size,
+            remaining,
+        })
+    }
+
+    /// Sets the list operation and filter parameters that the paginator walks through, which is
+    /// required to persist its position with [`Paginator::cursor`].
+    pub fn scope(mut self, scope: PaginationScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sets the direction in which to walk through the pages.
+    pub fn direction(mut self, direction: PaginationDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the number of items to request per page.
+    ///
+    /// This is a hint: the last page is shortened to not retrieve more than [`Paginator::max_items`].
+    pub fn page_size(mut self, page_size: u8) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the maximum number of items to yield in total.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        self.max_items
+            .map(|max_items| max_items.saturating_sub(self.yielded))
+    }
+
+    fn next_page_request(&self) -> PageRequest {
+        let cursor = self.cursor.clone();
+        let (after, before) = match self.direction {
+            PaginationDirection::Forward => (cursor, None),
+            PaginationDirection::Backward => (None, cursor),
+        };
+
+        let remaining = self
+            .remaining()
+            .map(|remaining| u8::try_from(remaining + self.skip).unwrap_or(u8::MAX));
+
+        PageRequest {
+            order: self.order,
+            after,
+            before,
+            limit: match (self.page_size, remaining) {
+                (Some(page_size), Some(remaining)) => Some(page_size.min(remaining)),
+                (page_size, _) => page_size,
+            },
+        }
+    }
+}
+
+// The paginator never pins its fields in place: the pending future is boxed.
+impl<T, F, Fut> Unpin for Paginator<T, F, Fut> {}
+
+impl<T, E, F, Fut> Stream for Paginator<T, F, Fut>
+where
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = WorkOsResult<PaginatedList<T>, E>>,
+{
+    type Item = WorkOsResult<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.remaining() == Some(0) {
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = this.buffer.pop_front() {
+                this.yielded += 1;
+                this.page_offset += 1;
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            let pending = match &mut this.pending {
+                Some(pending) => pending,
+                None => {
+                    let request = this.next_page_request();
+                    this.pending.insert(Box::pin((this.fetch)(request)))
+                }
+            };
+
+            let result = match pending.as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            this.pending = None;
+
+            match result {
+                Ok(page) => {
+                    let next_cursor = match this.direction {
+                        PaginationDirection::Forward => page.metadata.after,
+                        PaginationDirection::Backward => page.metadata.before,
+                    };
+
+                    this.finished = next_cursor.is_none() || page.data.is_empty();
+                    this.page_cursor = std::mem::replace(&mut this.cursor, next_cursor);
+                    this.page_offset = this.skip.min(page.data.len());
+                    this.buffer.extend(page.data.into_iter().skip(this.skip));
+                    this.skip = 0;
+                }
+                Err(err) => {
+                    this.finished = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod test {
+    use futures_util::{StreamExt, TryStreamExt};
+    use mockito::Matcher;
+    use serde_json::json;
+
+    use crate::organizations::{ListOrganizations, ListOrganizationsParams, OrganizationId};
+    use crate::{ApiKey, WorkOs, WorkOsError};
+
+    use super::*;
+
+    fn organization(id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "object": "organization",
+            "name": "Foo Corp",
+            "allow_profiles_outside_organization": false,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "domains": []
+        })
+    }
+
+    #[tokio::test]
+    async fn it_walks_forward_through_all_pages() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::Exact("order=desc&limit=2".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        organization("org_01EHQMYV6MBK39QC5PZXHY59C3"),
+                        organization("org_01EHQMVDTC2GRAHFCCRNTSKH46")
+                    ],
+                    "list_metadata": {
+                        "before": null,
+                        "after": "org_01EHQMVDTC2GRAHFCCRNTSKH46"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("limit".to_string(), "2".to_string()),
+                Matcher::UrlEncoded(
+                    "after".to_string(),
+                    "org_01EHQMVDTC2GRAHFCCRNTSKH46".to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        organization("org_01EGP9Z6RY2J6YE0ZV57CGEXV2")
+                    ],
+                    "list_metadata": {
+                        "before": "org_01EGP9Z6RY2J6YE0ZV57CGEXV2",
+                        "after": null
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let organizations = &workos.organizations();
+
+        let ids = Paginator::new(&PaginationParams::default(), |page| async move {
+            organizations
+                .list_organizations(&ListOrganizationsParams {
+                    pagination: page.pagination_params(),
+                    ..Default::default()
+                })
+                .await
+        })
+        .page_size(2)
+        .map_ok(|organization| organization.id)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+        assert_eq!(
+            ids,
+            vec![
+                OrganizationId::from("org_01EHQMYV6MBK39QC5PZXHY59C3"),
+                OrganizationId::from("org_01EHQMVDTC2GRAHFCCRNTSKH46"),
+                OrganizationId::from("org_01EGP9Z6RY2J6YE0ZV57CGEXV2"),
+            ]
+        )
+    }
+
+    #[tokio::test]
+    async fn it_walks_backward_and_stops_at_the_item_cap() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("limit".to_string(), "1".to_string()),
+                Matcher::UrlEncoded(
+                    "before".to_string(),
+                    "org_01EHQMVDTC2GRAHFCCRNTSKH46".to_string(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        organization("org_01EHQMYV6MBK39QC5PZXHY59C3")
+                    ],
+                    "list_metadata": {
+                        "before": "org_01EHQMYV6MBK39QC5PZXHY59C3",
+                        "after": "org_01EHQMYV6MBK39QC5PZXHY59C3"
+                    }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let organizations = &workos.organizations();
+
+        let ids = Paginator::new(
+            &PaginationParams {
+                before: Some("org_01EHQMVDTC2GRAHFCCRNTSKH46"),
+                ..Default::default()
+            },
+            |page| async move {
+                organizations
+                    .list_organizations(&ListOrganizationsParams {
+                        pagination: page.pagination_params(),
+                        ..Default::default()
+                    })
+                    .await
+            },
+        )
+        .page_size(10)
+        .max_items(1)
+        .map_ok(|organization| organization.id)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            ids,
+            vec![OrganizationId::from("org_01EHQMYV6MBK39QC5PZXHY59C3")]
+        )
+    }
+
+    #[tokio::test]
+    async fn it_resumes_from_a_serialized_cursor() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let first_page = server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::Exact("order=desc&limit=2".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        organization("org_01EHQMYV6MBK39QC5PZXHY59C3"),
+                        organization("org_01EHQMVDTC2GRAHFCCRNTSKH46")
+                    ],
+                    "list_metadata": {
+                        "before": null,
+                        "after": null
+                    }
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let organizations = &workos.organizations();
+        let scope = PaginationScope::new("list_organizations", &ListOrganizationsParams::default())
+            .unwrap();
+        let fetch = |page: PageRequest| async move {
+            organizations
+                .list_organizations(&ListOrganizationsParams {
+                    pagination: page.pagination_params(),
+                    ..Default::default()
+                })
+                .await
+        };
+
+        let mut paginator = Paginator::new(&PaginationParams::default(), fetch)
+            .scope(scope.clone())
+            .page_size(2);
+
+        assert_eq!(
+            paginator
+                .try_next()
+                .await
+                .unwrap()
+                .map(|organization| organization.id),
+            Some(OrganizationId::from("org_01EHQMYV6MBK39QC5PZXHY59C3"))
+        );
+
+        let cursor = serde_json::to_string(&paginator.cursor()).unwrap();
+        let cursor: Option<PaginationCursor> = serde_json::from_str(&cursor).unwrap();
+
+        assert_eq!(
+            cursor,
+            Some(PaginationCursor {
+                scope: PaginationScope {
+                    operation: "list_organizations".to_string(),
+                    params: json!({
+                        "order": "desc",
+                        "after": null,
+                        "before": null,
+                        "limit": null,
+                        "domains[]": null
+                    }),
+                },
+                order: PaginationOrder::Desc,
+                direction: PaginationDirection::Forward,
+                cursor: None,
+                offset: 1,
+                page_size: Some(2),
+                remaining: None,
+            })
+        );
+
+        let ids = Paginator::resume(&cursor.unwrap(), &scope, fetch)
+            .unwrap()
+            .map_ok(|organization| organization.id)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        first_page.assert_async().await;
+        assert_eq!(
+            ids,
+            vec![OrganizationId::from("org_01EHQMVDTC2GRAHFCCRNTSKH46")]
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_cursor_from_another_scope() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+        let organizations = &workos.organizations();
+        let fetch = |page: PageRequest| async move {
+            organizations
+                .list_organizations(&ListOrganizationsParams {
+                    pagination: page.pagination_params(),
+                    ..Default::default()
+                })
+                .await
+        };
+
+        let cursor = PaginationCursor {
+            scope: PaginationScope::new("list_organizations", &ListOrganizationsParams::default())
+                .unwrap(),
+            order: PaginationOrder::Desc,
+            direction: PaginationDirection::Forward,
+            cursor: Some("org_01EHQMVDTC2GRAHFCCRNTSKH46".to_string()),
+            offset: 0,
+            page_size: None,
+            remaining: None,
+        };
+
+        let other_operation =
+            PaginationScope::new("list_users", &ListOrganizationsParams::default()).unwrap();
+
+        assert!(matches!(
+            Paginator::resume(&cursor, &other_operation, fetch),
+            Err(ResumePaginationError::OperationMismatch { expected, cursor })
+                if expected == "list_users" && cursor == "list_organizations"
+        ));
+
+        let other_params = PaginationScope::new(
+            "list_organizations",
+            &ListOrganizationsParams {
+                domains: Some(vec!["foo-corp.com"].into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert!(matches!(
+            Paginator::resume(&cursor, &other_params, fetch),
+            Err(ResumePaginationError::ParamsMismatch)
+        ));
+
+        assert!(Paginator::resume(&cursor, &cursor.scope, fetch).is_ok())
+    }
+
+    #[tokio::test]
+    async fn it_ends_after_yielding_an_error() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::Any)
+            .with_status(401)
+            .create_async()
+            .await;
+
+        let organizations = &workos.organizations();
+
+        let results = Paginator::new(&PaginationParams::default(), |page| async move {
+            organizations
+                .list_organizations(&ListOrganizationsParams {
+                    pagination: page.pagination_params(),
+                    ..Default::default()
+                })
+                .await
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        assert!(matches!(
+            results.as_slice(),
+            [Err(WorkOsError::Unauthorized)]
+        ))
+    }
+}
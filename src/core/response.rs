@@ -1,6 +1,9 @@
-use reqwest::{Response, StatusCode};
+use http::StatusCode;
 
-use crate::{JsonOrText, WorkOsError, WorkOsResult};
+use crate::{ApiError, HttpResponse, WorkOsError, WorkOsResult, parse_retry_after};
+
+/// The response header containing the unique ID of the request.
+pub(crate) const REQUEST_ID_HEADER: &str = "X-Request-ID";
 
 pub trait ResponseExt
 where
@@ -10,57 +13,256 @@ where
     /// [`WorkOsError::Unauthorized`] response.
     fn handle_unauthorized_error<E>(self) -> WorkOsResult<Self, E>;
 
-    /// Handles a generic error from the WorkOS API by converting it into a
-    /// [`WorkOsError::RequestError`] response.
-    async fn handle_generic_error<E>(self) -> WorkOsResult<Self, E>;
+    /// Handles a generic error from the WorkOS API by converting it into an [`ApiError`]
+    /// wrapped in the [`WorkOsError`] variant matching the status code.
+    fn handle_generic_error<E>(self) -> WorkOsResult<Self, E>;
+
+    /// Handles an error from the WorkOS API by converting it into a [`WorkOsError::Operation`]
+    /// if `f` recognizes it, falling back to [`ResponseExt::handle_generic_error`] otherwise.
+    fn handle_operation_error<E, F>(self, f: F) -> WorkOsResult<Self, E>
+    where
+        F: FnOnce(&ApiError) -> Option<E>;
 
     /// Handles an unauthorized or generic error from the WorkOS API.
-    async fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E>;
+    fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E>;
 }
 
-impl ResponseExt for Response {
+impl ResponseExt for HttpResponse {
     fn handle_unauthorized_error<E>(self) -> WorkOsResult<Self, E> {
-        if self.status() == StatusCode::UNAUTHORIZED {
+        if self.status == StatusCode::UNAUTHORIZED {
             Err(WorkOsError::Unauthorized)
         } else {
             Ok(self)
         }
     }
 
-    async fn handle_generic_error<E>(self) -> WorkOsResult<Self, E> {
-        let status = self.status();
-
-        if status.is_client_error() || status.is_server_error() {
-            if self
-                .headers()
-                .get("content-type")
-                .and_then(|value| value.to_str().ok())
-                .is_some_and(|value| value.to_lowercase().starts_with("application/json"))
-            {
-                match self.json().await {
-                    Ok(value) => Err(WorkOsError::Unknown {
-                        status,
-                        body: JsonOrText::Json(value),
-                    }),
-                    Err(err) => Err(WorkOsError::RequestError(err)),
-                }
-            } else {
-                match self.text().await {
-                    Ok(text) => Err(WorkOsError::Unknown {
-                        status,
-                        body: JsonOrText::Text(text),
-                    }),
-                    Err(err) => Err(WorkOsError::RequestError(err)),
-                }
-            }
-        } else {
-            Ok(self)
+    fn handle_generic_error<E>(self) -> WorkOsResult<Self, E> {
+        self.handle_operation_error(|_| None)
+    }
+
+    fn handle_operation_error<E, F>(self, f: F) -> WorkOsResult<Self, E>
+    where
+        F: FnOnce(&ApiError) -> Option<E>,
+    {
+        let status = self.status;
+
+        if !status.is_client_error() && !status.is_server_error() {
+            return Ok(self);
         }
+
+        let error = ApiError::from_response(&self);
+
+        if let Some(error) = f(&error) {
+            return Err(WorkOsError::Operation(error));
+        }
+
+        let error = Box::new(error);
+
+        Err(match status {
+            StatusCode::NOT_FOUND => WorkOsError::NotFound(error),
+            StatusCode::CONFLICT => WorkOsError::Conflict(error),
+            StatusCode::UNPROCESSABLE_ENTITY => WorkOsError::UnprocessableEntity(error),
+            StatusCode::TOO_MANY_REQUESTS => WorkOsError::RateLimited {
+                retry_after: parse_retry_after(&self.headers),
+                error,
+            },
+            _ => WorkOsError::Unknown(error),
+        })
+    }
+
+    fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E> {
+        self.handle_unauthorized_error()?.handle_generic_error()
     }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use http::HeaderMap;
+    use http::header::HeaderValue;
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::{ApiFieldError, JsonOrText};
+
+    use super::*;
+
+    fn response(status: StatusCode, body: serde_json::Value) -> HttpResponse {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            REQUEST_ID_HEADER,
+            HeaderValue::from_static("req_01H945H0YD4F97JN9MATX7BYAG"),
+        );
+        headers.insert("Retry-After", HeaderValue::from_static("30"));
+
+        HttpResponse {
+            status,
+            headers,
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    #[test]
+    fn it_parses_a_not_found_error() {
+        let result = response(
+            StatusCode::NOT_FOUND,
+            json!({
+                "code": "entity_not_found",
+                "message": "Could not find user with id 'user_123'.",
+                "entity_id": "user_123"
+            }),
+        )
+        .handle_generic_error::<()>();
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::NotFound(error)) if error.status == StatusCode::NOT_FOUND
+                && error.code.as_deref() == Some("entity_not_found")
+                && error.message.as_deref() == Some("Could not find user with id 'user_123'.")
+                && error.request_id.as_deref() == Some("req_01H945H0YD4F97JN9MATX7BYAG")
+                && matches!(&error.body, JsonOrText::Json(body) if body["entity_id"] == "user_123")
+        )
+    }
+
+    #[test]
+    fn it_parses_field_level_validation_errors() {
+        let result = response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            json!({
+                "code": "invalid_request_parameters",
+                "message": "Validation failed",
+                "errors": [
+                    {
+                        "field": "email",
+                        "code": "email_invalid",
+                        "message": "Email is invalid."
+                    }
+                ]
+            }),
+        )
+        .handle_generic_error::<()>();
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::UnprocessableEntity(error)) if error.errors == vec![ApiFieldError {
+                field: Some("email".to_string()),
+                code: Some("email_invalid".to_string()),
+                message: Some("Email is invalid.".to_string()),
+            }]
+        )
+    }
+
+    #[test]
+    fn it_keeps_the_code_and_message_when_a_field_level_error_is_malformed() {
+        let result = response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            json!({
+                "code": "invalid_request_parameters",
+                "message": "Validation failed",
+                "errors": [
+                    "email is invalid",
+                    {
+                        "field": "email",
+                        "code": "email_invalid",
+                        "message": "Email is invalid."
+                    }
+                ]
+            }),
+        )
+        .handle_generic_error::<()>();
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::UnprocessableEntity(error)) if error.code.as_deref() == Some("invalid_request_parameters")
+                && error.message.as_deref() == Some("Validation failed")
+                && error.errors == vec![ApiFieldError {
+                    field: Some("email".to_string()),
+                    code: Some("email_invalid".to_string()),
+                    message: Some("Email is invalid.".to_string()),
+                }]
+        )
+    }
+
+    #[test]
+    fn it_prefers_the_code_and_message_over_the_oauth_error_fields() {
+        let result = response(
+            StatusCode::BAD_REQUEST,
+            json!({
+                "code": "email_verification_required",
+                "message": "Email ownership must be verified before authentication.",
+                "error": "invalid_grant",
+                "error_description": "Email ownership must be verified before authentication.",
+                "errors": [
+                    {
+                        "field": "email",
+                        "code": "email_unverified",
+                        "message": "Email is not verified."
+                    }
+                ]
+            }),
+        )
+        .handle_generic_error::<()>();
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Unknown(error)) if error.code.as_deref() == Some("email_verification_required")
+                && error.message.as_deref() == Some("Email ownership must be verified before authentication.")
+                && error.errors.len() == 1
+        )
+    }
+
+    #[test]
+    fn it_parses_an_oauth_error() {
+        let result = response(
+            StatusCode::BAD_REQUEST,
+            json!({
+                "error": "invalid_grant",
+                "error_description": "The code has expired."
+            }),
+        )
+        .handle_generic_error::<()>();
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Unknown(error)) if error.code.as_deref() == Some("invalid_grant")
+                && error.message.as_deref() == Some("The code has expired.")
+        )
+    }
+
+    #[test]
+    fn it_parses_a_rate_limited_error() {
+        let result = response(
+            StatusCode::TOO_MANY_REQUESTS,
+            json!({
+                "message": "Too many requests"
+            }),
+        )
+        .handle_generic_error::<()>();
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            }) if retry_after == Duration::from_secs(30)
+        )
+    }
+
+    #[test]
+    fn it_keeps_a_text_body() {
+        let result = HttpResponse {
+            status: StatusCode::BAD_GATEWAY,
+            headers: HeaderMap::new(),
+            body: b"Bad Gateway".to_vec(),
+        }
+        .handle_generic_error::<()>();
 
-    async fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E> {
-        self.handle_unauthorized_error()?
-            .handle_generic_error()
-            .await
+        assert_matches!(
+            result,
+            Err(WorkOsError::Unknown(error)) if error.status == StatusCode::BAD_GATEWAY
+                && error.code.is_none()
+                && matches!(&error.body, JsonOrText::Text(text) if text == "Bad Gateway")
+        )
     }
 }
@@ -0,0 +1,325 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use http::{HeaderMap, StatusCode};
+
+use crate::{HttpResponse, REQUEST_ID_HEADER, WorkOsError, parse_retry_after};
+
+thread_local! {
+    /// The slot that receives the metadata of responses while a [`ResponseMetaFuture`] is polled.
+    ///
+    /// The outer [`Option`] is [`None`] when no metadata is being captured.
+    static CAPTURED_META: RefCell<Option<Option<ResponseMeta>>> = const { RefCell::new(None) };
+}
+
+/// The metadata of a response from the WorkOS API.
+#[derive(Clone, Debug)]
+pub struct ResponseMeta {
+    /// The status code of the response.
+    pub status: StatusCode,
+
+    /// The headers of the response.
+    pub headers: HeaderMap,
+
+    /// The ID of the request, taken from the `X-Request-ID` response header.
+    pub request_id: Option<String>,
+
+    /// The rate limit information of the response.
+    pub rate_limit: RateLimit,
+
+    /// The time elapsed between sending the request and receiving the response, including retries.
+    pub elapsed: Duration,
+}
+
+impl ResponseMeta {
+    pub(crate) fn from_response(response: &HttpResponse, elapsed: Duration) -> Self {
+        Self {
+            status: response.status,
+            headers: response.headers.clone(),
+            request_id: header_str(&response.headers, REQUEST_ID_HEADER).map(String::from),
+            rate_limit: RateLimit::from_headers(&response.headers),
+            elapsed,
+        }
+    }
+}
+
+/// The rate limit information of a response from the WorkOS API.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The maximum number of requests allowed in the current window, taken from the
+    /// `X-RateLimit-Limit` response header.
+    pub limit: Option<u64>,
+
+    /// The number of requests remaining in the current window, taken from the
+    /// `X-RateLimit-Remaining` response header.
+    pub remaining: Option<u64>,
+
+    /// The value of the `X-RateLimit-Reset` response header.
+    pub reset: Option<u64>,
+
+    /// The delay after which requests may be retried, taken from the `Retry-After` response header.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimit {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let parse = |name| header_str(headers, name).and_then(|value| value.trim().parse().ok());
+
+        Self {
+            limit: parse("X-RateLimit-Limit"),
+            remaining: parse("X-RateLimit-Remaining"),
+            reset: parse("X-RateLimit-Reset"),
+            retry_after: parse_retry_after(headers),
+        }
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+/// Records the metadata of a response if it is being captured by a [`ResponseMetaFuture`].
+pub(crate) fn capture_response_meta(response: &HttpResponse, elapsed: Duration) {
+    CAPTURED_META.with_borrow_mut(|captured| {
+        if let Some(meta) = captured {
+            *meta = Some(ResponseMeta::from_response(response, elapsed));
+        }
+    });
+}
+
+/// The output or error of an operation together with the metadata of its response.
+#[derive(Clone, Debug)]
+pub struct WithResponseMeta<T> {
+    /// The output or error of the operation.
+    pub data: T,
+
+    /// The metadata of the last response received by the operation.
+    ///
+    /// This is [`None`] for operations that do not send a request, such as building URLs, and for
+    /// errors that occur before a response is received.
+    pub meta: Option<ResponseMeta>,
+}
+
+impl<T> WithResponseMeta<T> {
+    /// Returns the output or error of the operation, discarding the metadata.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for WithResponseMeta<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.data.fmt(f)
+    }
+}
+
+impl<T: Error> Error for WithResponseMeta<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.data.source()
+    }
+}
+
+impl<E> From<WithResponseMeta<WorkOsError<E>>> for WorkOsError<E> {
+    fn from(error: WithResponseMeta<WorkOsError<E>>) -> Self {
+        error.data
+    }
+}
+
+/// An extension trait to capture the [`ResponseMeta`] of an operation.
+pub trait ResponseMetaExt: Future + Sized {
+    /// Captures the metadata of the response received by the operation.
+    ///
+    /// The metadata is attached to both the output and the error of the operation, so the request ID
+    /// of a failed request, such as one that was rate limited, remains available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos::WorkOsResult;
+    /// # use workos::organizations::*;
+    /// use workos::{ApiKey, ResponseMetaExt, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), GetOrganizationError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let organization = workos
+    ///     .organizations()
+    ///     .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+    ///     .with_response_meta()
+    ///     .await?;
+    ///
+    /// if let Some(meta) = organization.meta {
+    ///     println!("request ID: {:?}", meta.request_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_response_meta(self) -> ResponseMetaFuture<Self>;
+}
+
+impl<F, T, E> ResponseMetaExt for F
+where
+    F: Future<Output = Result<T, E>> + Unpin,
+{
+    fn with_response_meta(self) -> ResponseMetaFuture<Self> {
+        ResponseMetaFuture {
+            future: self,
+            meta: None,
+        }
+    }
+}
+
+/// The future returned by [`ResponseMetaExt::with_response_meta`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ResponseMetaFuture<F> {
+    future: F,
+    meta: Option<ResponseMeta>,
+}
+
+impl<F, T, E> Future for ResponseMetaFuture<F>
+where
+    F: Future<Output = Result<T, E>> + Unpin,
+{
+    type Output = Result<WithResponseMeta<T>, WithResponseMeta<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let previous = CAPTURED_META.replace(Some(this.meta.take()));
+        let poll = Pin::new(&mut this.future).poll(cx);
+        this.meta = CAPTURED_META.replace(previous).flatten();
+
+        let Poll::Ready(result) = poll else {
+            return Poll::Pending;
+        };
+
+        let meta = this.meta.take();
+
+        Poll::Ready(match result {
+            Ok(data) => Ok(WithResponseMeta { data, meta }),
+            Err(data) => Err(WithResponseMeta { data, meta }),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod test {
+    use serde_json::json;
+
+    use crate::organizations::{GetOrganization, OrganizationId};
+    use crate::{ApiKey, WorkOs, WorkOsError};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_captures_the_response_meta_of_an_operation() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(200)
+            .with_header("X-Request-ID", "req_01H945H0YD4F97JN9MATX7BYAG")
+            .with_header("X-RateLimit-Limit", "6000")
+            .with_header("X-RateLimit-Remaining", "5999")
+            .with_header("X-RateLimit-Reset", "60")
+            .with_body(
+                json!({
+                  "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                  "object": "organization",
+                  "name": "Foo Corporation",
+                  "allow_profiles_outside_organization": false,
+                  "created_at": "2021-06-25T19:07:33.155Z",
+                  "updated_at": "2021-06-25T19:07:33.155Z",
+                  "domains": []
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let organization = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .with_response_meta()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            organization.data.id,
+            OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
+        );
+
+        let meta = organization.meta.unwrap();
+
+        assert_eq!(meta.status, StatusCode::OK);
+        assert_eq!(
+            meta.request_id.as_deref(),
+            Some("req_01H945H0YD4F97JN9MATX7BYAG")
+        );
+        assert_eq!(
+            meta.rate_limit,
+            RateLimit {
+                limit: Some(6000),
+                remaining: Some(5999),
+                reset: Some(60),
+                retry_after: None,
+            }
+        )
+    }
+
+    #[tokio::test]
+    async fn it_captures_the_response_meta_of_a_failed_operation() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(429)
+            .with_header("X-Request-ID", "req_01H945H0YD4F97JN9MATX7BYAG")
+            .with_header("Retry-After", "30")
+            .with_body(
+                json!({
+                    "code": "rate_limit_exceeded",
+                    "message": "Too many requests"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let error = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .with_response_meta()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.data, WorkOsError::RateLimited { .. }));
+
+        let meta = error.meta.unwrap();
+
+        assert_eq!(meta.status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            meta.request_id.as_deref(),
+            Some("req_01H945H0YD4F97JN9MATX7BYAG")
+        );
+        assert_eq!(meta.rate_limit.retry_after, Some(Duration::from_secs(30)))
+    }
+}
@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use http::header::{HeaderMap, RETRY_AFTER};
+use http::{Method, StatusCode};
+
+/// The policy used to retry failed requests to the WorkOS API.
+///
+/// Only requests that are safe to repeat are retried: requests using an idempotent HTTP method
+/// (`GET`, `HEAD`, `OPTIONS`, `PUT` and `DELETE`) and requests that carry an `Idempotency-Key` header.
+///
+/// A request is retried when it fails to connect, times out or receives one of the following
+/// responses: `408 Request Timeout`, `429 Too Many Requests`, `500 Internal Server Error`,
+/// `502 Bad Gateway`, `503 Service Unavailable` or `504 Gateway Timeout`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the initial request.
+    ///
+    /// A value of `1` disables retries.
+    pub max_attempts: u32,
+
+    /// The delay before the first retry, which is doubled for every subsequent retry.
+    pub base_delay: Duration,
+
+    /// The upper limit on the delay between two attempts.
+    ///
+    /// If a `Retry-After` header asks for a longer delay, the response is returned without retrying.
+    pub max_delay: Duration,
+
+    /// Whether to apply random jitter to the delay between two attempts.
+    pub jitter: bool,
+
+    /// Whether to wait for the delay given in the `Retry-After` header of a response, if present.
+    pub respect_retry_after: bool,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries requests.
+    pub const fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            jitter: false,
+            respect_retry_after: false,
+        }
+    }
+
+    /// Whether a request with the given method and headers may be retried.
+    pub(crate) fn is_retryable_request(&self, method: &Method, headers: &HeaderMap) -> bool {
+        matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+        ) || headers.contains_key("Idempotency-Key")
+    }
+
+    /// Whether a response with the given status code should be retried.
+    pub(crate) fn is_retryable_status(&self, status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::REQUEST_TIMEOUT
+                | StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// Returns the delay to wait before the given retry, starting at `1` for the first retry.
+    ///
+    /// Returns [`None`] if the request should not be retried because the `Retry-After` header
+    /// asks for a longer delay than [`RetryPolicy::max_delay`].
+    pub(crate) fn delay(&self, retry: u32, headers: Option<&HeaderMap>) -> Option<Duration> {
+        if self.respect_retry_after
+            && let Some(retry_after) = headers.and_then(parse_retry_after)
+        {
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+
+        let exponent = retry.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        Some(if self.jitter {
+            delay.mul_f64(fastrand::f64())
+        } else {
+            delay
+        })
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+/// Parses the `Retry-After` header, which is either a number of seconds or an HTTP date.
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+
+    Some(
+        (date.to_utc() - chrono::Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use http::header::HeaderValue;
+
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            jitter: false,
+            respect_retry_after: true,
+        }
+    }
+
+    #[test]
+    fn it_backs_off_exponentially_up_to_the_max_delay() {
+        let policy = policy();
+
+        assert_eq!(policy.delay(1, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(2, None), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(3, None), Some(Duration::from_millis(400)));
+        assert_eq!(policy.delay(5, None), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn it_keeps_jittered_delays_within_the_backoff() {
+        let policy = RetryPolicy {
+            jitter: true,
+            ..policy()
+        };
+
+        for retry in 1..10 {
+            assert!(policy.delay(retry, None).unwrap() <= Duration::from_secs(1))
+        }
+    }
+
+    #[test]
+    fn it_honors_the_retry_after_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("1"));
+
+        assert_eq!(
+            policy().delay(1, Some(&headers)),
+            Some(Duration::from_secs(1))
+        );
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+
+        assert_eq!(policy().delay(1, Some(&headers)), None)
+    }
+
+    #[test]
+    fn it_parses_a_retry_after_http_date() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert_eq!(parse_retry_after(&headers), Some(Duration::ZERO))
+    }
+
+    #[test]
+    fn it_only_retries_idempotent_requests() {
+        let policy = policy();
+        let mut headers = HeaderMap::new();
+
+        assert!(policy.is_retryable_request(&Method::GET, &headers));
+        assert!(policy.is_retryable_request(&Method::DELETE, &headers));
+        assert!(!policy.is_retryable_request(&Method::POST, &headers));
+
+        headers.insert("Idempotency-Key", HeaderValue::from_static("key"));
+
+        assert!(policy.is_retryable_request(&Method::POST, &headers))
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(feature = "reqwest")]
+mod reqwest_transport;
+
+use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use http::{HeaderMap, Method, StatusCode};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use url::Url;
+
+#[cfg(feature = "reqwest")]
+pub use reqwest_transport::*;
+
+/// An HTTP request to the WorkOS API.
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    /// The request method.
+    pub method: Method,
+
+    /// The request URL, including the query string.
+    pub url: Url,
+
+    /// The request headers.
+    pub headers: HeaderMap,
+
+    /// The request body.
+    pub body: Option<Vec<u8>>,
+
+    /// The total timeout of the request, overriding the timeout of the transport, if any.
+    pub timeout: Option<Duration>,
+}
+
+/// An HTTP response from the WorkOS API.
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    /// The response status code.
+    pub status: StatusCode,
+
+    /// The response headers.
+    pub headers: HeaderMap,
+
+    /// The response body.
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Deserializes the response body as JSON.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, RequestError> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// Returns the response body as text, replacing invalid UTF-8 sequences.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// An error returned from an [`HttpTransport`].
+#[derive(Debug, Error)]
+pub enum TransportError {
+    /// The connection to the server could not be established.
+    #[error("connection error")]
+    Connect(#[source] Box<dyn Error + Send + Sync>),
+
+    /// The request timed out.
+    #[error("timeout")]
+    Timeout(#[source] Box<dyn Error + Send + Sync>),
+
+    /// Any other error that occurred while sending the request or receiving the response.
+    #[error("transport error")]
+    Other(#[source] Box<dyn Error + Send + Sync>),
+}
+
+/// An error that occurred while sending a request to the WorkOS API.
+#[derive(Debug, Error)]
+pub enum RequestError {
+    /// The request could not be built.
+    #[error("failed to build request")]
+    Build(#[source] Box<dyn Error + Send + Sync>),
+
+    /// The request could not be sent by the HTTP transport.
+    #[error(transparent)]
+    Transport(#[from] TransportError),
+
+    /// The response body could not be decoded.
+    #[error("failed to decode response body")]
+    Decode(#[from] serde_json::Error),
+}
+
+/// The HTTP transport used to send requests to the WorkOS API.
+///
+/// An implementation based on [`reqwest`](https://docs.rs/reqwest) is available as [`ReqwestTransport`]
+/// when the `reqwest` feature is enabled, which it is by default.
+///
+/// On `wasm32` targets the returned future is not required to be [`Send`], so implementations use
+/// `#[async_trait(?Send)]` there.
+///
+/// # Examples
+///
+/// ```
+/// use async_trait::async_trait;
+/// use workos::http::{HeaderMap, StatusCode};
+/// use workos::{ApiKey, HttpRequest, HttpResponse, HttpTransport, TransportError, WorkOs};
+///
+/// struct NotFoundTransport;
+///
+/// #[async_trait]
+/// impl HttpTransport for NotFoundTransport {
+///     async fn send(&self, _request: HttpRequest) -> Result<HttpResponse, TransportError> {
+///         Ok(HttpResponse {
+///             status: StatusCode::NOT_FOUND,
+///             headers: HeaderMap::new(),
+///             body: Vec::new(),
+///         })
+///     }
+/// }
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .transport(NotFoundTransport)
+///     .build()
+///     .unwrap();
+/// ```
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait HttpTransport: Send + Sync {
+    /// Sends the request and returns the response.
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        (**self).send(request).await
+    }
+}
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+
+use crate::{HttpRequest, HttpResponse, HttpTransport, TransportError};
+
+/// An [`HttpTransport`] backed by a [`reqwest::Client`].
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Returns a new [`ReqwestTransport`] using the provided client.
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(client: reqwest::Client) -> Self {
+        Self::new(client)
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(err: reqwest::Error) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        if err.is_connect() {
+            return TransportError::Connect(Box::new(err));
+        }
+
+        if err.is_timeout() {
+            TransportError::Timeout(Box::new(err))
+        } else {
+            TransportError::Other(Box::new(err))
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl HttpTransport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let mut builder = self
+            .client
+            .request(request.method, request.url)
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = builder.send().await?;
+
+        Ok(HttpResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes().await?.to_vec(),
+        })
+    }
+}
@@ -2,6 +2,8 @@ mod api_key;
 mod metadata;
 mod paginated_list;
 mod pagination_params;
+mod request_options;
+mod secret_string;
 mod timestamps;
 mod unpaginated_list;
 mod url_encodable_vec;
@@ -10,6 +12,8 @@ pub use api_key::*;
 pub use metadata::*;
 pub use paginated_list::*;
 pub use pagination_params::*;
+pub use request_options::*;
+pub use secret_string::*;
 pub use timestamps::*;
 pub use unpaginated_list::*;
 pub(crate) use url_encodable_vec::*;
@@ -1,7 +1,16 @@
-use derive_more::{Deref, Display, From};
+use derive_more::From;
 use serde::Serialize;
 
+use crate::SecretString;
+
 /// An API key to authenticate with the WorkOS API.
-#[derive(Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Clone, Debug, From, PartialEq, Eq, Serialize)]
 #[from(forward)]
-pub struct ApiKey(String);
+pub struct ApiKey(SecretString);
+
+impl ApiKey {
+    /// Returns the value of the API key.
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
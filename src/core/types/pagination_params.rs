@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// The parameters used to control pagination for a given paginated endpoint.
 #[derive(Clone, Debug, Serialize)]
@@ -28,7 +28,7 @@ impl Default for PaginationParams<'_> {
 }
 
 /// The order in which records should be returned when paginating.
-#[derive(Clone, Copy, Debug, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PaginationOrder {
     /// Records are returned in ascending order.
@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+use http::HeaderMap;
+
+use crate::ApiKey;
+
+/// Options that override the configuration of the client for the requests of an operation.
+///
+/// See [`WorkOs::with_options`](crate::WorkOs::with_options).
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions<'a> {
+    /// The total timeout of each request, overriding the timeout of the client.
+    ///
+    /// Custom [`HttpTransport`](crate::HttpTransport)s are responsible for applying it, see [`HttpRequest::timeout`](crate::HttpRequest::timeout).
+    pub timeout: Option<Duration>,
+
+    /// Additional headers to send with each request.
+    pub headers: Option<&'a HeaderMap>,
+
+    /// The API key to authenticate the requests with, overriding the API key of the client.
+    pub api_key: Option<&'a ApiKey>,
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A string holding a secret, such as an API key or a token.
+///
+/// The secret is redacted from the [`Debug`] output, can only be read through
+/// [`SecretString::expose_secret`] and is zeroized when dropped.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Returns the secret value.
+    ///
+    /// Take care not to log or otherwise leak the returned value.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_owned())
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// A placeholder for a secret in a [`Debug`] output.
+pub(crate) struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretString {}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::SecretString;
+
+    #[test]
+    fn it_redacts_the_secret_from_the_debug_output() {
+        let secret = SecretString::from("sk_example_123456789");
+
+        assert_eq!(format!("{secret:?}"), "[REDACTED]");
+        assert_eq!(secret.expose_secret(), "sk_example_123456789")
+    }
+
+    #[test]
+    fn it_serializes_the_secret_as_a_string() {
+        let secret: SecretString = serde_json::from_value(json!("sk_example_123456789")).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&secret).unwrap(),
+            json!("sk_example_123456789")
+        )
+    }
+}
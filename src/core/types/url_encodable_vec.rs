@@ -42,77 +42,39 @@ where
 
 #[cfg(test)]
 mod test {
-    use mockito::Matcher;
-    use reqwest::StatusCode;
     use serde::Serialize;
 
     use super::*;
 
-    #[tokio::test]
-    async fn it_serializes_a_vec_in_the_query_string() {
+    #[test]
+    fn it_serializes_a_vec_in_the_query_string() {
         #[derive(Debug, Serialize)]
         struct List<'a> {
             #[serde(rename = "items[]")]
             pub items: UrlEncodableVec<&'a str>,
         }
 
-        let mut server = mockito::Server::new_async().await;
+        let query = serde_urlencoded::to_string(List {
+            items: UrlEncodableVec(vec!["one", "two", "three"]),
+        })
+        .unwrap();
 
-        server
-            .mock("GET", "/")
-            .match_query(Matcher::UrlEncoded(
-                "items[]".to_string(),
-                "one,two,three".to_string(),
-            ))
-            .with_status(200)
-            .create_async()
-            .await;
-
-        let client = reqwest::Client::new();
-
-        let response = client
-            .get(server.url())
-            .query(&List {
-                items: UrlEncodableVec(vec!["one", "two", "three"]),
-            })
-            .send()
-            .await
-            .unwrap();
-
-        assert_eq!(response.status(), StatusCode::OK)
+        assert_eq!(query, "items%5B%5D=one%2Ctwo%2Cthree")
     }
 
-    #[tokio::test]
-    async fn it_serializes_a_vec_in_an_option_in_the_query_string() {
+    #[test]
+    fn it_serializes_a_vec_in_an_option_in_the_query_string() {
         #[derive(Debug, Serialize)]
         struct List<'a> {
             #[serde(rename = "items[]")]
             pub items: Option<UrlEncodableVec<&'a str>>,
         }
 
-        let mut server = mockito::Server::new_async().await;
-
-        server
-            .mock("GET", "/")
-            .match_query(Matcher::UrlEncoded(
-                "items[]".to_string(),
-                "one,two,three".to_string(),
-            ))
-            .with_status(200)
-            .create_async()
-            .await;
-
-        let client = reqwest::Client::new();
-
-        let response = client
-            .get(server.url())
-            .query(&List {
-                items: Some(UrlEncodableVec(vec!["one", "two", "three"])),
-            })
-            .send()
-            .await
-            .unwrap();
+        let query = serde_urlencoded::to_string(List {
+            items: Some(UrlEncodableVec(vec!["one", "two", "three"])),
+        })
+        .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK)
+        assert_eq!(query, "items%5B%5D=one%2Ctwo%2Cthree")
     }
 }
@@ -15,7 +15,8 @@ impl From<GetDirectoryError> for WorkOsError<GetDirectoryError> {
 }
 
 /// [WorkOS Docs: Get a Directory](https://workos.com/docs/reference/directory-sync/directory/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetDirectory {
     /// Get the details of an existing directory.
     ///
@@ -41,27 +42,41 @@ pub trait GetDirectory {
     async fn get_directory(&self, id: &DirectoryId) -> WorkOsResult<Directory, GetDirectoryError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetDirectory for DirectorySync<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_directory",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/directories/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_directory(&self, id: &DirectoryId) -> WorkOsResult<Directory, GetDirectoryError> {
         let url = self.workos.base_url().join(&format!("/directories/{id}"))?;
         let directory = self
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Directory>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<Directory>()?;
 
         Ok(directory)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use serde_json::json;
@@ -78,7 +93,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directories/directory_01ECAZ4NV9QMV47GW873HDCX74")
@@ -119,7 +135,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directories/directory_01ECAZ4NV9QMV47GW873HDCX74")
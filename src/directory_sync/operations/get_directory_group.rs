@@ -15,7 +15,8 @@ impl From<GetDirectoryGroupError> for WorkOsError<GetDirectoryGroupError> {
 }
 
 /// [WorkOS Docs: Get a Directory Group](https://workos.com/docs/reference/directory-sync/group/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetDirectoryGroup {
     /// Get the details of an existing Directory Group.
     ///
@@ -46,8 +47,24 @@ pub trait GetDirectoryGroup {
     ) -> WorkOsResult<DirectoryGroup, GetDirectoryGroupError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetDirectoryGroup for DirectorySync<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_directory_group",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/directory_groups/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_directory_group(
         &self,
         id: &DirectoryGroupId,
@@ -60,19 +77,17 @@ impl GetDirectoryGroup for DirectorySync<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<DirectoryGroup>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<DirectoryGroup>()?;
 
         Ok(directory_group)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use serde_json::json;
@@ -89,7 +104,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -134,7 +150,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -15,7 +15,8 @@ impl From<GetDirectoryUserError> for WorkOsError<GetDirectoryUserError> {
 }
 
 /// [WorkOS Docs: Get a Directory User](https://workos.com/docs/reference/directory-sync/user/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetDirectoryUser {
     /// Get the details of an existing Directory User.
     ///
@@ -46,8 +47,24 @@ pub trait GetDirectoryUser {
     ) -> WorkOsResult<DirectoryUser, GetDirectoryUserError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetDirectoryUser for DirectorySync<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_directory_user",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/directory_users/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_directory_user(
         &self,
         id: &DirectoryUserId,
@@ -60,19 +77,17 @@ impl GetDirectoryUser for DirectorySync<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<DirectoryUser>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<DirectoryUser>()?;
 
         Ok(directory_user)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use serde_json::json;
@@ -89,7 +104,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -156,7 +172,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_users/")
@@ -20,7 +20,8 @@ pub struct ListDirectoriesParams<'a> {
 }
 
 /// [WorkOS Docs: List Directories](https://workos.com/docs/reference/directory-sync/directory/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListDirectories {
     /// Get a list of all of your existing directories matching the criteria specified.
     ///
@@ -51,8 +52,24 @@ pub trait ListDirectories {
     ) -> WorkOsResult<PaginatedList<Directory>, ()>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListDirectories for DirectorySync<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "list_directories",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/directories",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn list_directories(
         &self,
         params: &ListDirectoriesParams<'_>,
@@ -63,19 +80,17 @@ impl ListDirectories for DirectorySync<'_> {
             .client()
             .get(url)
             .query(&params)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<Directory>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<PaginatedList<Directory>>()?;
 
         Ok(directories)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -92,7 +107,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directories")
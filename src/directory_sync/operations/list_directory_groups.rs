@@ -19,7 +19,8 @@ pub struct ListDirectoryGroupsParams<'a> {
 }
 
 /// [WorkOS Docs: List Directory Groups](https://workos.com/docs/reference/directory-sync/group/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListDirectoryGroups {
     /// Get a list of all of existing directory groups matching the criteria specified.
     ///
@@ -50,8 +51,24 @@ pub trait ListDirectoryGroups {
     ) -> WorkOsResult<PaginatedList<DirectoryGroup>, ()>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListDirectoryGroups for DirectorySync<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "list_directory_groups",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/directory_groups",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn list_directory_groups(
         &self,
         params: &ListDirectoryGroupsParams<'_>,
@@ -62,19 +79,17 @@ impl ListDirectoryGroups for DirectorySync<'_> {
             .client()
             .get(url)
             .query(&params)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<DirectoryGroup>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<PaginatedList<DirectoryGroup>>()?;
 
         Ok(directory_groups)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -92,7 +107,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_groups")
@@ -155,7 +171,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_groups")
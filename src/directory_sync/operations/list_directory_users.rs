@@ -19,7 +19,8 @@ pub struct ListDirectoryUsersParams<'a> {
 }
 
 /// [WorkOS Docs: List Directory Users](https://workos.com/docs/reference/directory-sync/user/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListDirectoryUsers {
     /// Get a list of all of existing Directory Users matching the criteria specified.
     ///
@@ -50,8 +51,24 @@ pub trait ListDirectoryUsers {
     ) -> WorkOsResult<PaginatedList<DirectoryUser>, ()>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListDirectoryUsers for DirectorySync<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "list_directory_users",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/directory_users",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn list_directory_users(
         &self,
         params: &ListDirectoryUsersParams<'_>,
@@ -62,19 +79,17 @@ impl ListDirectoryUsers for DirectorySync<'_> {
             .client()
             .get(url)
             .query(&params)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<DirectoryUser>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<PaginatedList<DirectoryUser>>()?;
 
         Ok(directory_users)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -92,7 +107,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_users")
@@ -219,7 +235,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_users")
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use derive_more::{Deref, Display, From};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
 use crate::{Timestamp, events::*};
 
@@ -17,7 +17,7 @@ pub struct EventId(String);
 pub struct EventContext(pub HashMap<String, String>);
 
 /// The type of an [`Event`].
-#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EventName {
     /// [WorkOS Docs: `authentication.email_verification_failed` event](https://workos.com/docs/events/authentication).
     #[display("authentication.email_verification_failed")]
@@ -298,11 +298,16 @@ pub enum EventName {
     #[display("user.updated")]
     #[serde(rename = "user.updated")]
     UserUpdated,
+
+    /// An event type that is not known to this version of the SDK.
+    #[display("{_0}")]
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The data of the [`Event`].
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(tag = "event", content = "data")]
+#[serde(remote = "Self", tag = "event", content = "data")]
 pub enum EventData {
     /// [WorkOS Docs: `authentication.email_verification_failed` event](https://workos.com/docs/events/authentication).
     #[serde(rename = "authentication.email_verification_failed")]
@@ -527,6 +532,51 @@ pub enum EventData {
     /// [WorkOS Docs: `user.updated` event](https://workos.com/docs/events/user)
     #[serde(rename = "user.updated")]
     UserUpdated(UserUpdatedEvent),
+
+    /// An event that is not known to this version of the SDK.
+    #[serde(untagged, skip_deserializing)]
+    Unknown {
+        /// The type of the event.
+        event: String,
+
+        /// The raw data of the event.
+        data: serde_json::Value,
+    },
+}
+
+impl Serialize for EventData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EventData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Fields {
+            event: EventName,
+            data: serde_json::Value,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+
+        if let EventName::Unknown(event) = fields.event {
+            return Ok(Self::Unknown {
+                event,
+                data: fields.data,
+            });
+        }
+
+        let known = serde_json::json!({ "event": fields.event, "data": fields.data });
+
+        Self::deserialize(known).map_err(de::Error::custom)
+    }
 }
 
 /// [WorkOS Docs: Event](https://workos.com/docs/reference/event)
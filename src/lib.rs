@@ -1,11 +1,22 @@
 //! Rust SDK for interacting with the [WorkOS](https://workos.com) API.
+//!
+//! # WebAssembly
+//!
+//! The crate supports the `wasm32-unknown-unknown` target, e.g. for edge workers, with the default
+//! `reqwest` transport using the Fetch API. On this target the futures of the operations are not
+//! [`Send`], the HTTP client options of [`WorkOsBuilder`] that require a native HTTP client are not
+//! available, and the `blocking`, `native-tls` and `rustls-tls` features have no effect, as TLS is
+//! handled by the JavaScript runtime.
 
 #![warn(missing_docs)]
 
 mod core;
 mod known_or_unknown;
 mod workos;
+mod workos_object;
 
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
 pub mod directory_sync;
 pub mod events;
 pub mod mfa;
@@ -15,8 +26,12 @@ pub mod portal;
 pub mod roles;
 pub mod sso;
 pub mod user_management;
+pub mod webhooks;
 pub mod widgets;
 
+pub use http;
+
 pub use crate::core::*;
 pub use crate::workos::*;
 pub use known_or_unknown::*;
+pub use workos_object::*;
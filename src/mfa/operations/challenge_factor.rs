@@ -40,7 +40,8 @@ pub struct ChallengeFactorParams<'a> {
 pub enum ChallengeFactorError {}
 
 /// [WorkOS Docs: Challenge Factor](https://workos.com/docs/reference/mfa/challenge-factor)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ChallengeFactor {
     /// Creates a Challenge for an Authentication Factor.
     ///
@@ -74,8 +75,24 @@ pub trait ChallengeFactor {
     ) -> WorkOsResult<AuthenticationChallenge, ChallengeFactorError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ChallengeFactor for Mfa<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "challenge_factor",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/auth/factors/{id}/challenge",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn challenge_factor(
         &self,
         params: &ChallengeFactorParams<'_>,
@@ -88,20 +105,18 @@ impl ChallengeFactor for Mfa<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<AuthenticationChallenge>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<AuthenticationChallenge>()?;
 
         Ok(challenge)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -118,7 +133,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -166,7 +182,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
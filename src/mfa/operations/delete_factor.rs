@@ -15,7 +15,8 @@ impl From<DeleteFactorError> for WorkOsError<DeleteFactorError> {
 }
 
 /// [WorkOS Docs: Delete Factor](https://workos.com/docs/reference/mfa/delete-factor)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteFactor {
     /// Permanently deletes an Authentication Factor. It cannot be undone.
     ///
@@ -44,8 +45,24 @@ pub trait DeleteFactor {
     ) -> WorkOsResult<(), DeleteFactorError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteFactor for Mfa<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "delete_factor",
+            skip_all,
+            fields(
+                http.request.method = "DELETE",
+                url.template = "/auth/factors/{authentication_factor_id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn delete_factor(
         &self,
         authentication_factor_id: &AuthenticationFactorId,
@@ -58,17 +75,16 @@ impl DeleteFactor for Mfa<'_> {
         self.workos
             .client()
             .delete(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?;
+            .handle_unauthorized_or_generic_error()?;
 
         Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use tokio;
 
@@ -83,7 +99,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
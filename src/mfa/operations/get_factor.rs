@@ -15,7 +15,8 @@ impl From<GetFactorError> for WorkOsError<GetFactorError> {
 }
 
 /// [WorkOS Docs: Get Factor](https://workos.com/docs/reference/mfa/get-factor)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetFactor {
     /// Gets an Authentication Factor.
     ///
@@ -44,8 +45,24 @@ pub trait GetFactor {
     ) -> WorkOsResult<AuthenticationFactor, GetFactorError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetFactor for Mfa<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_factor",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/auth/factors/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_factor(
         &self,
         id: &AuthenticationFactorId,
@@ -59,19 +76,17 @@ impl GetFactor for Mfa<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<AuthenticationFactor>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<AuthenticationFactor>()?;
 
         Ok(organization)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -87,7 +102,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/auth/factors/auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ")
@@ -1,9 +1,11 @@
+use std::fmt;
+
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationChallengeId, Mfa, MfaCode};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{Redacted, ResponseExt, WorkOsResult};
 
 /// The response for [`VerifyChallenge`].
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,7 +19,9 @@ pub struct VerifyChallengeResponse {
 }
 
 /// The parameters for [`VerifyChallenge`].
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+///
+/// The code is redacted from the [`Debug`] output.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct VerifyChallengeParams<'a> {
     /// The unique ID of the authentication Challenge.
     #[serde(skip)]
@@ -27,12 +31,25 @@ pub struct VerifyChallengeParams<'a> {
     pub code: &'a MfaCode,
 }
 
+impl fmt::Debug for VerifyChallengeParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerifyChallengeParams")
+            .field(
+                "authentication_challenge_id",
+                &self.authentication_challenge_id,
+            )
+            .field("code", &Redacted)
+            .finish()
+    }
+}
+
 /// An error returned from [`VerifyChallenge`].
 #[derive(Debug, Error)]
 pub enum VerifyChallengeError {}
 
 /// [WorkOS Docs: Verify Challenge](https://workos.com/docs/reference/mfa/verify-challenge)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait VerifyChallenge {
     /// Verify Authentication Challenge.
     ///
@@ -66,8 +83,24 @@ pub trait VerifyChallenge {
     ) -> WorkOsResult<VerifyChallengeResponse, VerifyChallengeError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl VerifyChallenge for Mfa<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "verify_challenge",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/auth/challenges/{id}/verify",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn verify_challenge(
         &self,
         params: &VerifyChallengeParams<'_>,
@@ -80,20 +113,18 @@ impl VerifyChallenge for Mfa<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<VerifyChallengeResponse>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<VerifyChallengeResponse>()?;
 
         Ok(verify_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -110,7 +141,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -153,4 +185,19 @@ mod test {
             AuthenticationChallengeId::from("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5")
         )
     }
+
+    #[test]
+    fn it_redacts_the_code_from_the_debug_output() {
+        let params = VerifyChallengeParams {
+            authentication_challenge_id: &AuthenticationChallengeId::from(
+                "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            ),
+            code: &MfaCode::from("123456"),
+        };
+
+        let debug = format!("{params:?}");
+
+        assert!(debug.contains("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5"));
+        assert!(!debug.contains("123456"))
+    }
 }
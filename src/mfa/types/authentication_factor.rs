@@ -1,7 +1,7 @@
 use derive_more::{Deref, Display, From};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::Timestamps;
+use crate::{KnownOrUnknown, Timestamps};
 
 /// The ID of an [`AuthenticationFactor`].
 #[derive(
@@ -28,7 +28,7 @@ pub struct AuthenticationFactorIdAndType {
     pub id: AuthenticationFactorId,
 
     /// The type of the authentication factor.
-    pub r#type: AuthenticationFactorTypeString,
+    pub r#type: KnownOrUnknown<AuthenticationFactorTypeString, String>,
 }
 
 /// [WorkOS Docs: Authentication Factor](https://workos.com/docs/reference/mfa/authentication-factor)
@@ -63,7 +63,7 @@ pub struct UserAuthenticationFactor {
 }
 
 /// The type of an [`AuthenticationFactor`].
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum UserAuthenticationFactorType {
     /// Time-based one-time password (TOTP).
@@ -79,10 +79,48 @@ pub enum UserAuthenticationFactorType {
         /// The phone number the factor was enrolled with.
         phone_number: String,
     },
+    /// A type of authentication factor that is not known to this version of the SDK.
+    #[serde(untagged)]
+    Unknown {
+        /// The type of the authentication factor.
+        r#type: String,
+    },
+}
+
+impl<'de> Deserialize<'de> for UserAuthenticationFactorType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum KnownType {
+            Totp { issuer: String, user: String },
+            Sms { phone_number: String },
+        }
+
+        #[derive(Deserialize)]
+        struct Fields {
+            r#type: String,
+
+            #[serde(flatten)]
+            known: Option<KnownType>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+
+        Ok(match fields.known {
+            Some(KnownType::Totp { issuer, user }) => Self::Totp { issuer, user },
+            Some(KnownType::Sms { phone_number }) => Self::Sms { phone_number },
+            None => Self::Unknown {
+                r#type: fields.r#type,
+            },
+        })
+    }
 }
 
 /// The type of an [`AuthenticationFactor`].
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AuthenticationFactorType {
     /// Time-based one-time password (TOTP).
@@ -110,13 +148,74 @@ pub enum AuthenticationFactorType {
         /// The phone number the factor was enrolled with.
         phone_number: String,
     },
+    /// A type of authentication factor that is not known to this version of the SDK.
+    #[serde(untagged)]
+    Unknown {
+        /// The type of the authentication factor.
+        r#type: String,
+    },
+}
+
+impl<'de> Deserialize<'de> for AuthenticationFactorType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum KnownType {
+            Totp {
+                issuer: String,
+                user: String,
+                #[serde(default)]
+                qr_code: String,
+                #[serde(default)]
+                secret: String,
+                #[serde(default)]
+                uri: String,
+            },
+            Sms {
+                phone_number: String,
+            },
+        }
+
+        #[derive(Deserialize)]
+        struct Fields {
+            r#type: String,
+
+            #[serde(flatten)]
+            known: Option<KnownType>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+
+        Ok(match fields.known {
+            Some(KnownType::Totp {
+                issuer,
+                user,
+                qr_code,
+                secret,
+                uri,
+            }) => Self::Totp {
+                issuer,
+                user,
+                qr_code,
+                secret,
+                uri,
+            },
+            Some(KnownType::Sms { phone_number }) => Self::Sms { phone_number },
+            None => Self::Unknown {
+                r#type: fields.r#type,
+            },
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use serde_json::json;
 
-    use crate::{Timestamp, Timestamps};
+    use crate::{KnownOrUnknown, Timestamp, Timestamps};
 
     use super::*;
 
@@ -187,4 +286,112 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_deserializes_an_unknown_factor() {
+        let factor: AuthenticationFactor = serde_json::from_str(
+            &json!({
+                "object": "authentication_factor",
+                "id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+                "created_at": "2022-02-15T15:14:19.392Z",
+                "updated_at": "2022-02-15T15:14:19.392Z",
+                "type": "webauthn",
+                "webauthn": {
+                    "credential_id": "cred_123"
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            factor,
+            AuthenticationFactor {
+                id: AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"),
+                r#type: AuthenticationFactorType::Unknown {
+                    r#type: "webauthn".to_string()
+                },
+                timestamps: Timestamps {
+                    created_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
+                    updated_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
+                },
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_a_user_totp_factor() {
+        let factor: UserAuthenticationFactor = serde_json::from_str(
+            &json!({
+                "object": "authentication_factor",
+                "id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+                "created_at": "2022-02-15T15:14:19.392Z",
+                "updated_at": "2022-02-15T15:14:19.392Z",
+                "type": "totp",
+                "totp": {
+                    "issuer": "Foo Corp",
+                    "user": "alan.turing@foo-corp.com"
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            factor.r#type,
+            UserAuthenticationFactorType::Totp {
+                issuer: "Foo Corp".to_string(),
+                user: "alan.turing@foo-corp.com".to_string(),
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_an_unknown_user_factor() {
+        let factor: UserAuthenticationFactor = serde_json::from_str(
+            &json!({
+                "object": "authentication_factor",
+                "id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+                "created_at": "2022-02-15T15:14:19.392Z",
+                "updated_at": "2022-02-15T15:14:19.392Z",
+                "type": "webauthn",
+                "webauthn": {
+                    "credential_id": "cred_123"
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            factor,
+            UserAuthenticationFactor {
+                id: AuthenticationFactorId::from("auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ"),
+                r#type: UserAuthenticationFactorType::Unknown {
+                    r#type: "webauthn".to_string()
+                },
+                timestamps: Timestamps {
+                    created_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
+                    updated_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
+                },
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_an_unknown_factor_type_string() {
+        let factor: AuthenticationFactorIdAndType = serde_json::from_str(
+            &json!({
+                "id": "auth_factor_01FVYZ5QM8N98T9ME5BCB2BBMJ",
+                "type": "webauthn"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            factor.r#type,
+            KnownOrUnknown::Unknown("webauthn".to_string())
+        )
+    }
 }
@@ -15,7 +15,8 @@ impl From<DeleteOrganizationDomainError> for WorkOsError<DeleteOrganizationDomai
 }
 
 /// [WorkOS Docs: Delete an Organization Domain](https://workos.com/docs/reference/domain-verification/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteOrganizationDomain {
     /// Permanently deletes an organization domain in the current environment. It cannot be undone.
     ///
@@ -46,8 +47,24 @@ pub trait DeleteOrganizationDomain {
     ) -> WorkOsResult<(), DeleteOrganizationDomainError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteOrganizationDomain for OrganizationDomains<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "delete_organization_domain",
+            skip_all,
+            fields(
+                http.request.method = "DELETE",
+                url.template = "/organization_domains/{organization_domain_id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn delete_organization_domain(
         &self,
         organization_domain_id: &OrganizationDomainId,
@@ -60,17 +77,16 @@ impl DeleteOrganizationDomain for OrganizationDomains<'_> {
         self.workos
             .client()
             .delete(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?;
+            .handle_unauthorized_or_generic_error()?;
 
         Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use tokio;
 
@@ -85,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -15,7 +15,8 @@ impl From<GetOrganizationDomainError> for WorkOsError<GetOrganizationDomainError
 }
 
 /// [WorkOS Docs: Get an Organization Domain](https://workos.com/docs/reference/domain-verification/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetOrganizationDomain {
     /// Get the details of an existing organization domain.
     ///
@@ -44,8 +45,24 @@ pub trait GetOrganizationDomain {
     ) -> WorkOsResult<OrganizationDomain, GetOrganizationDomainError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetOrganizationDomain for OrganizationDomains<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_organization_domain",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/organization_domains/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_organization_domain(
         &self,
         id: &OrganizationDomainId,
@@ -59,19 +76,17 @@ impl GetOrganizationDomain for OrganizationDomains<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<OrganizationDomain>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<OrganizationDomain>()?;
 
         Ok(organization_domain)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -87,7 +102,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
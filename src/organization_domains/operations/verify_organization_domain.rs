@@ -15,7 +15,8 @@ impl From<VerifyOrganizationDomainError> for WorkOsError<VerifyOrganizationDomai
 }
 
 /// [WorkOS Docs: Verify an Organization Domain](https://workos.com/docs/reference/domain-verification/verify)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait VerifyOrganizationDomain {
     /// Initiates verification process for an Organization Domain.
     ///
@@ -46,8 +47,24 @@ pub trait VerifyOrganizationDomain {
     ) -> WorkOsResult<OrganizationDomain, VerifyOrganizationDomainError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl VerifyOrganizationDomain for OrganizationDomains<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "verify_organization_domain",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/organization_domains/{organization_domain_id}/verify",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn verify_organization_domain(
         &self,
         organization_domain_id: &OrganizationDomainId,
@@ -60,19 +77,17 @@ impl VerifyOrganizationDomain for OrganizationDomains<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<OrganizationDomain>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<OrganizationDomain>()?;
 
         Ok(organization)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -89,7 +104,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -15,7 +15,8 @@ impl From<DeleteOrganizationError> for WorkOsError<DeleteOrganizationError> {
 }
 
 /// [WorkOS Docs: Delete an Organization](https://workos.com/docs/reference/organization/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteOrganization {
     /// Permanently deletes an organization in the current environment. It cannot be undone.
     ///
@@ -44,8 +45,24 @@ pub trait DeleteOrganization {
     ) -> WorkOsResult<(), DeleteOrganizationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteOrganization for Organizations<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "delete_organization",
+            skip_all,
+            fields(
+                http.request.method = "DELETE",
+                url.template = "/organizations/{organization_id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn delete_organization(
         &self,
         organization_id: &OrganizationId,
@@ -58,17 +75,16 @@ impl DeleteOrganization for Organizations<'_> {
         self.workos
             .client()
             .delete(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?;
+            .handle_unauthorized_or_generic_error()?;
 
         Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use tokio;
 
@@ -83,7 +99,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("DELETE", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
@@ -15,7 +15,8 @@ impl From<GetOrganizationError> for WorkOsError<GetOrganizationError> {
 }
 
 /// [WorkOS Docs: Get an Organization](https://workos.com/docs/reference/organization/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetOrganization {
     /// Get the details of an existing organization.
     ///
@@ -44,8 +45,24 @@ pub trait GetOrganization {
     ) -> WorkOsResult<Organization, GetOrganizationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetOrganization for Organizations<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_organization",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/organizations/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_organization(
         &self,
         id: &OrganizationId,
@@ -59,19 +76,17 @@ impl GetOrganization for Organizations<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Organization>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<Organization>()?;
 
         Ok(organization)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -87,7 +102,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
@@ -40,7 +40,8 @@ impl From<ListOrganizationsError> for WorkOsError<ListOrganizationsError> {
 }
 
 /// [WorkOS Docs: List Organizations](https://workos.com/docs/reference/organization/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListOrganizations {
     /// Get a list of all of your existing organizations matching the criteria specified.
     ///
@@ -72,8 +73,24 @@ pub trait ListOrganizations {
     ) -> WorkOsResult<PaginatedList<Organization>, ()>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListOrganizations for Organizations<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "list_organizations",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/organizations",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn list_organizations(
         &self,
         params: &ListOrganizationsParams<'_>,
@@ -84,19 +101,17 @@ impl ListOrganizations for Organizations<'_> {
             .client()
             .get(url)
             .query(&params)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<Organization>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<PaginatedList<Organization>>()?;
 
         Ok(organizations)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -114,7 +129,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/organizations")
@@ -184,7 +200,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/organizations")
@@ -36,7 +36,8 @@ pub struct GeneratePortalLinkResponse {
 pub enum GeneratePortalLinkError {}
 
 /// [WorkOS Docs: Generate a Portal Link](https://workos.com/docs/reference/admin-portal/portal-link/generate)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GeneratePortalLink {
     /// Generate a Portal Link scoped to an Organization.
     ///
@@ -71,8 +72,24 @@ pub trait GeneratePortalLink {
     ) -> WorkOsResult<GeneratePortalLinkResponse, GeneratePortalLinkError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GeneratePortalLink for Portal<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "generate_portal_link",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/portal/generate_link",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn generate_portal_link(
         &self,
         params: &GeneratePortalLinkParams<'_>,
@@ -82,20 +99,18 @@ impl GeneratePortalLink for Portal<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<GeneratePortalLinkResponse>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<GeneratePortalLinkResponse>()?;
 
         Ok(response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -113,7 +128,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&dbg!(server.url()))
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/portal/generate_link")
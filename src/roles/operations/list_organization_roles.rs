@@ -25,7 +25,8 @@ impl From<ListOrganizationRolesError> for WorkOsError<ListOrganizationRolesError
 }
 
 /// [WorkOS Docs: List roles for an organization](https://workos.com/docs/reference/roles/list-for-organization)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListOrganizationRoles {
     /// Get a list of all roles for the provided organization in priority order.
     ///
@@ -59,8 +60,24 @@ pub trait ListOrganizationRoles {
     ) -> WorkOsResult<UnpaginatedList<Role>, ListOrganizationRolesError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListOrganizationRoles for Roles<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "list_organization_roles",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/organizations/{organization_id}/roles",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn list_organization_roles(
         &self,
         params: &ListOrganizationRolesParams,
@@ -77,19 +94,17 @@ impl ListOrganizationRoles for Roles<'_> {
             .client()
             .get(url)
             .query(&params)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<UnpaginatedList<Role>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<UnpaginatedList<Role>>()?;
 
         Ok(roles)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -107,7 +122,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT/roles")
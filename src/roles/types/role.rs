@@ -1,7 +1,7 @@
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
-use crate::Timestamps;
+use crate::{KnownOrUnknown, Timestamps};
 
 /// The ID of a [`Role`].
 #[derive(
@@ -52,7 +52,7 @@ pub struct Role {
     pub permissions: Vec<String>,
 
     /// The type of role.
-    pub r#type: RoleType,
+    pub r#type: KnownOrUnknown<RoleType, String>,
 
     /// The timestamps for the role.
     #[serde(flatten)]
@@ -72,3 +72,29 @@ pub struct RoleEvent {
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::KnownOrUnknown;
+
+    use super::RoleType;
+
+    #[test]
+    fn it_deserializes_known_role_types() {
+        assert_eq!(
+            serde_json::from_value::<KnownOrUnknown<RoleType, String>>(json!("EnvironmentRole"))
+                .unwrap(),
+            KnownOrUnknown::Known(RoleType::EnvironmentRole)
+        )
+    }
+
+    #[test]
+    fn it_deserializes_unknown_role_types() {
+        assert_eq!(
+            serde_json::from_value::<KnownOrUnknown<RoleType, String>>(json!("TeamRole")).unwrap(),
+            KnownOrUnknown::Unknown("TeamRole".to_string())
+        )
+    }
+}
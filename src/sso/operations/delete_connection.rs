@@ -15,7 +15,8 @@ impl From<DeleteConnectionError> for WorkOsError<DeleteConnectionError> {
 }
 
 /// [WorkOS Docs: Delete a Connection](https://workos.com/docs/reference/sso/connection/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteConnection {
     /// Deletes a [`Connection`](crate::sso::Connection).
     ///
@@ -44,8 +45,24 @@ pub trait DeleteConnection {
     ) -> WorkOsResult<(), DeleteConnectionError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteConnection for Sso<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "delete_connection",
+            skip_all,
+            fields(
+                http.request.method = "DELETE",
+                url.template = "/connections/{connection_id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn delete_connection(
         &self,
         connection_id: &ConnectionId,
@@ -58,17 +75,16 @@ impl DeleteConnection for Sso<'_> {
         self.workos
             .client()
             .delete(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?;
+            .handle_unauthorized_or_generic_error()?;
 
         Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use tokio;
@@ -85,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("DELETE", "/connections/conn_01E2NPPCT7XQ2MVVYDHWGK1WN4")
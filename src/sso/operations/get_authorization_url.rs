@@ -1,5 +1,6 @@
-use url::{ParseError, Url};
+use url::Url;
 
+use crate::BuildUrlError;
 use crate::organizations::OrganizationId;
 use crate::sso::{ClientId, ConnectionId, Sso};
 
@@ -32,7 +33,9 @@ pub struct GetAuthorizationUrlParams<'a> {
     /// The client ID for the environment in which SSO is being initiated.
     ///
     /// This value can be obtained from the "Configuration" page in the WorkOS Dashboard.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    pub client_id: Option<&'a ClientId>,
 
     /// The redirect URI the user will be redirected to after successfully signing in.
     pub redirect_uri: &'a str,
@@ -53,17 +56,16 @@ pub trait GetAuthorizationUrl {
     /// # Examples
     ///
     /// ```
-    /// # use url::ParseError;
     /// # use workos::sso::*;
-    /// use workos::{ApiKey, WorkOs};
+    /// use workos::{ApiKey, BuildUrlError, WorkOs};
     ///
-    /// # fn run() -> Result<(), ParseError> {
+    /// # fn run() -> Result<(), BuildUrlError> {
     /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
     ///
     /// let authorization_url = workos
     ///     .sso()
     ///     .get_authorization_url(&GetAuthorizationUrlParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         redirect_uri: "https://your-app.com/callback",
     ///         connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
     ///             "conn_01E4ZCR3C56J083X43JQXF3JK5",
@@ -74,11 +76,17 @@ pub trait GetAuthorizationUrl {
     /// # }
     /// # run().unwrap();
     /// ```
-    fn get_authorization_url(&self, params: &GetAuthorizationUrlParams) -> Result<Url, ParseError>;
+    fn get_authorization_url(
+        &self,
+        params: &GetAuthorizationUrlParams,
+    ) -> Result<Url, BuildUrlError>;
 }
 
 impl GetAuthorizationUrl for Sso<'_> {
-    fn get_authorization_url(&self, params: &GetAuthorizationUrlParams) -> Result<Url, ParseError> {
+    fn get_authorization_url(
+        &self,
+        params: &GetAuthorizationUrlParams,
+    ) -> Result<Url, BuildUrlError> {
         let GetAuthorizationUrlParams {
             connection_selector,
             client_id,
@@ -87,7 +95,11 @@ impl GetAuthorizationUrl for Sso<'_> {
         } = params;
 
         let query = {
-            let client_id = client_id.to_string();
+            let client_id = self
+                .workos
+                .client_id_or_default(*client_id)
+                .ok_or(BuildUrlError::MissingClientId)?
+                .to_string();
 
             let connection_selector_param = match connection_selector {
                 ConnectionSelector::Connection(connection_id) => {
@@ -118,13 +130,14 @@ impl GetAuthorizationUrl for Sso<'_> {
             String::from(querystring::stringify(query_params).trim_end_matches('&'))
         };
 
-        self.workos
-            .base_url()
-            .join(&format!("/sso/authorize?{query}"))
+        Ok(self
+            .workos
+            .auth_base_url()
+            .join(&format!("/sso/authorize?{query}"))?)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use crate::{ApiKey, WorkOs};
 
@@ -137,7 +150,7 @@ mod test {
         let authorization_url = workos
             .sso()
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 redirect_uri: "https://your-app.com/callback",
                 connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
                     "conn_1234",
@@ -162,7 +175,7 @@ mod test {
 
         let authorization_url = workos_sso
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 redirect_uri: "https://your-app.com/callback",
                 connection_selector: ConnectionSelector::Organization(&OrganizationId::from(
                     "org_1234",
@@ -187,7 +200,7 @@ mod test {
 
         let authorization_url = workos_sso
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 redirect_uri: "https://your-app.com/callback",
                 connection_selector: ConnectionSelector::Provider(&Provider::GoogleOauth),
                 state: None,
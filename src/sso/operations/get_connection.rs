@@ -15,7 +15,8 @@ impl From<GetConnectionError> for WorkOsError<GetConnectionError> {
 }
 
 /// [WorkOS Docs: Get a Connection](https://workos.com/docs/reference/sso/connection/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetConnection {
     /// Retrieves a [`Connection`] by its ID.
     ///
@@ -44,8 +45,24 @@ pub trait GetConnection {
     ) -> WorkOsResult<Connection, GetConnectionError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetConnection for Sso<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_connection",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/connections/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_connection(
         &self,
         id: &ConnectionId,
@@ -55,19 +72,17 @@ impl GetConnection for Sso<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Connection>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<Connection>()?;
 
         Ok(connection)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use serde_json::json;
@@ -84,7 +99,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/connections/conn_01E4ZCR3C56J083X43JQXF3JK5")
@@ -132,7 +148,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/connections/conn_01E4ZCR3C56J083X43JQXF3JK5")
@@ -9,7 +9,8 @@ use crate::{ResponseExt, WorkOsResult};
 pub enum GetProfileError {}
 
 /// [WorkOS Docs: Get a User Profile](https://workos.com/docs/reference/sso/profile/user)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetProfile {
     /// [WorkOS Docs: Get a User Profile](https://workos.com/docs/reference/sso/profile/user)
     ///
@@ -36,8 +37,24 @@ pub trait GetProfile {
     ) -> WorkOsResult<Profile, GetProfileError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetProfile for Sso<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_profile",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/sso/profile",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_profile(
         &self,
         access_token: &AccessToken,
@@ -47,19 +64,17 @@ impl GetProfile for Sso<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(access_token)
+            .bearer_auth(access_token.expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Profile>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<Profile>()?;
 
         Ok(get_profile_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -76,7 +91,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/sso/profile")
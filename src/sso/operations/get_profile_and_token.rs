@@ -1,22 +1,36 @@
+use std::fmt;
+
 use async_trait::async_trait;
-use reqwest::{Response, StatusCode};
+use http::StatusCode;
 use serde::Deserialize;
 use thiserror::Error;
 
 use crate::sso::{AccessToken, AuthorizationCode, ClientId, Profile, Sso};
-use crate::{WorkOsError, WorkOsResult};
+use crate::{HttpResponse, Redacted, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`GetProfileAndToken`].
-#[derive(Debug)]
+///
+/// The code is redacted from the [`Debug`] output.
 pub struct GetProfileAndTokenParams<'a> {
     /// The client ID corresponding to the environment that SSO was initiated
     /// from.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    pub client_id: Option<&'a ClientId>,
 
     /// The authorization code to exchange for the profile and token.
     pub code: &'a AuthorizationCode,
 }
 
+impl fmt::Debug for GetProfileAndTokenParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetProfileAndTokenParams")
+            .field("client_id", &self.client_id)
+            .field("code", &Redacted)
+            .finish()
+    }
+}
+
 /// The response for [`GetProfileAndToken`].
 #[derive(Debug, Deserialize)]
 pub struct GetProfileAndTokenResponse {
@@ -38,40 +52,31 @@ pub struct GetProfileAndTokenError {
     pub error_description: String,
 }
 
-#[async_trait]
 trait HandleGetProfileAndTokenError
 where
     Self: Sized,
 {
-    async fn handle_get_profile_and_token_error(
-        self,
-    ) -> WorkOsResult<Self, GetProfileAndTokenError>;
+    fn handle_get_profile_and_token_error(self) -> WorkOsResult<Self, GetProfileAndTokenError>;
 }
 
-#[async_trait]
-impl HandleGetProfileAndTokenError for Response {
-    async fn handle_get_profile_and_token_error(
-        self,
-    ) -> WorkOsResult<Self, GetProfileAndTokenError> {
-        match self.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => match err.status() {
-                Some(StatusCode::BAD_REQUEST) => {
-                    let error = self.json::<GetProfileAndTokenError>().await?;
-
-                    Err(match error.error.as_str() {
-                        "invalid_client" | "unauthorized_client" => WorkOsError::Unauthorized,
-                        _ => WorkOsError::Operation(error),
-                    })
-                }
-                _ => Err(WorkOsError::RequestError(err)),
-            },
+impl HandleGetProfileAndTokenError for HttpResponse {
+    fn handle_get_profile_and_token_error(self) -> WorkOsResult<Self, GetProfileAndTokenError> {
+        if self.status == StatusCode::BAD_REQUEST {
+            let error = self.json::<GetProfileAndTokenError>()?;
+
+            return Err(match error.error.as_str() {
+                "invalid_client" | "unauthorized_client" => WorkOsError::Unauthorized,
+                _ => WorkOsError::Operation(error),
+            });
         }
+
+        self.handle_generic_error()
     }
 }
 
 /// [WorkOS Docs: Get a Profile and Token](https://workos.com/docs/reference/sso/profile/token)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetProfileAndToken {
     /// [WorkOS Docs: Get a Profile and Token](https://workos.com/docs/reference/sso/profile/token)
     ///
@@ -88,7 +93,7 @@ pub trait GetProfileAndToken {
     /// let GetProfileAndTokenResponse { profile, .. } = workos
     ///     .sso()
     ///     .get_profile_and_token(&GetProfileAndTokenParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         code: &AuthorizationCode::from("01G6RSWVD06ZQ6JB4YS5W521S3"),
     ///     })
     ///     .await?;
@@ -101,18 +106,41 @@ pub trait GetProfileAndToken {
     ) -> WorkOsResult<GetProfileAndTokenResponse, GetProfileAndTokenError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetProfileAndToken for Sso<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_profile_and_token",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/sso/token",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_profile_and_token(
         &self,
         params: &GetProfileAndTokenParams<'_>,
     ) -> WorkOsResult<GetProfileAndTokenResponse, GetProfileAndTokenError> {
         let &GetProfileAndTokenParams { client_id, code } = params;
+        let client_id = self
+            .workos
+            .client_id_or_default(client_id)
+            .ok_or(WorkOsError::MissingClientId)?;
 
         let url = self.workos.base_url().join("/sso/token")?;
         let params = [
             ("client_id", &client_id.to_string()),
-            ("client_secret", &self.workos.key().to_string()),
+            (
+                "client_secret",
+                &self.workos.key().expose_secret().to_string(),
+            ),
             ("grant_type", &"authorization_code".to_string()),
             ("code", &code.to_string()),
         ];
@@ -123,16 +151,14 @@ impl GetProfileAndToken for Sso<'_> {
             .form(&params)
             .send()
             .await?
-            .handle_get_profile_and_token_error()
-            .await?
-            .json::<GetProfileAndTokenResponse>()
-            .await?;
+            .handle_get_profile_and_token_error()?
+            .json::<GetProfileAndTokenResponse>()?;
 
         Ok(get_profile_and_token_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use mockito::Matcher;
@@ -151,7 +177,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/sso/token")
@@ -187,7 +214,7 @@ mod test {
         let response = workos
             .sso()
             .get_profile_and_token(&GetProfileAndTokenParams {
-                client_id: &ClientId::from("client_1234"),
+                client_id: Some(&ClientId::from("client_1234")),
                 code: &AuthorizationCode::from("abc123"),
             })
             .await
@@ -210,7 +237,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/sso/token")
@@ -228,7 +256,7 @@ mod test {
         let result = workos
             .sso()
             .get_profile_and_token(&GetProfileAndTokenParams {
-                client_id: &ClientId::from("client_1234"),
+                client_id: Some(&ClientId::from("client_1234")),
                 code: &AuthorizationCode::from("abc123"),
             })
             .await;
@@ -243,7 +271,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/sso/token")
@@ -261,7 +290,7 @@ mod test {
         let result = workos
             .sso()
             .get_profile_and_token(&GetProfileAndTokenParams {
-                client_id: &ClientId::from("client_1234"),
+                client_id: Some(&ClientId::from("client_1234")),
                 code: &AuthorizationCode::from("abc123"),
             })
             .await;
@@ -276,7 +305,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/sso/token")
@@ -294,7 +324,7 @@ mod test {
         let result = workos
             .sso()
             .get_profile_and_token(&GetProfileAndTokenParams {
-                client_id: &ClientId::from("client_1234"),
+                client_id: Some(&ClientId::from("client_1234")),
                 code: &AuthorizationCode::from("abc123"),
             })
             .await;
@@ -309,4 +339,17 @@ mod test {
             panic!("expected get_profile_and_token to return an error")
         }
     }
+
+    #[test]
+    fn it_redacts_the_code_from_the_debug_output() {
+        let params = GetProfileAndTokenParams {
+            client_id: Some(&ClientId::from("client_1234")),
+            code: &AuthorizationCode::from("abc123"),
+        };
+
+        let debug = format!("{params:?}");
+
+        assert!(debug.contains("client_1234"));
+        assert!(!debug.contains("abc123"))
+    }
 }
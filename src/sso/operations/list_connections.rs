@@ -21,7 +21,8 @@ pub struct ListConnectionsParams<'a> {
 }
 
 /// [WorkOS Docs: List Connections](https://workos.com/docs/reference/sso/connection/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListConnections {
     /// Retrieves a list of [`Connection`]s.
     ///
@@ -52,8 +53,24 @@ pub trait ListConnections {
     ) -> WorkOsResult<PaginatedList<Connection>, ()>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListConnections for Sso<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "list_connections",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/connections",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn list_connections(
         &self,
         params: &ListConnectionsParams<'_>,
@@ -64,19 +81,17 @@ impl ListConnections for Sso<'_> {
             .client()
             .get(url)
             .query(&params)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<Connection>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<PaginatedList<Connection>>()?;
 
         Ok(connections)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -94,7 +109,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/connections")
@@ -154,7 +170,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/connections")
@@ -1,9 +1,16 @@
-use derive_more::{Deref, Display, From};
+use derive_more::From;
 use serde::{Deserialize, Serialize};
 
+use crate::SecretString;
+
 /// An access token that may be exchanged for a [`Profile`](crate::sso::Profile).
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct AccessToken(String);
+pub struct AccessToken(SecretString);
+
+impl AccessToken {
+    /// Returns the value of the access token.
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
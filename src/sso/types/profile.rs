@@ -14,7 +14,7 @@ use super::{ConnectionId, ConnectionType};
 pub struct ProfileId(String);
 
 /// [WorkOS Docs: Profile](https://workos.com/docs/reference/sso/profile)
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Profile {
     /// The ID of the profile.
     pub id: ProfileId,
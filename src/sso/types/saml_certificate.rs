@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Timestamp;
+use crate::{KnownOrUnknown, Timestamp};
 
 /// The state of an [`Invitation`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -20,7 +20,7 @@ pub enum SamlCertificateType {
 pub struct SamlCertificateEvent {
     /// The type of the certificate.
     #[serde(rename = "certificate_type")]
-    pub r#type: SamlCertificateType,
+    pub r#type: KnownOrUnknown<SamlCertificateType, String>,
 
     /// The timestamp indicating when the object was created.
     pub expiry_date: Timestamp,
@@ -28,3 +28,34 @@ pub struct SamlCertificateEvent {
     /// Whether the certificated is expired.
     pub is_expired: Option<bool>,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::KnownOrUnknown;
+
+    use super::SamlCertificateType;
+
+    #[test]
+    fn it_deserializes_known_certificate_types() {
+        assert_eq!(
+            serde_json::from_value::<KnownOrUnknown<SamlCertificateType, String>>(json!(
+                "ResponseSigning"
+            ))
+            .unwrap(),
+            KnownOrUnknown::Known(SamlCertificateType::ResponseSigning)
+        )
+    }
+
+    #[test]
+    fn it_deserializes_unknown_certificate_types() {
+        assert_eq!(
+            serde_json::from_value::<KnownOrUnknown<SamlCertificateType, String>>(json!(
+                "MetadataSigning"
+            ))
+            .unwrap(),
+            KnownOrUnknown::Unknown("MetadataSigning".to_string())
+        )
+    }
+}
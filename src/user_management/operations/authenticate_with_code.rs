@@ -1,3 +1,4 @@
+use std::fmt;
 use std::net::IpAddr;
 
 use async_trait::async_trait;
@@ -7,13 +8,18 @@ use crate::sso::{AuthorizationCode, ClientId};
 use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, Redacted, WorkOsError, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithCode`].
-#[derive(Debug, Serialize)]
+///
+/// The code verifier, code and invitation token are redacted from the [`Debug`] output.
+#[derive(Serialize)]
 pub struct AuthenticateWithCodeParams<'a> {
     /// Identifies the application making the request to the WorkOS server.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    #[serde(skip)]
+    pub client_id: Option<&'a ClientId>,
 
     /// The randomly generated string used to derive the code challenge that was passed to the authorization url as part of the PKCE flow.
     pub code_verifier: Option<&'a str>,
@@ -31,10 +37,26 @@ pub struct AuthenticateWithCodeParams<'a> {
     pub user_agent: Option<&'a str>,
 }
 
+impl fmt::Debug for AuthenticateWithCodeParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthenticateWithCodeParams")
+            .field("client_id", &self.client_id)
+            .field("code_verifier", &self.code_verifier.map(|_| Redacted))
+            .field("code", &Redacted)
+            .field("invitation_token", &self.invitation_token.map(|_| Redacted))
+            .field("ip_address", &self.ip_address)
+            .field("user_agent", &self.user_agent)
+            .finish()
+    }
+}
+
 #[derive(Serialize)]
 struct AuthenticateWithCodeBody<'a> {
+    /// Identifies the application making the request to the WorkOS server.
+    client_id: &'a ClientId,
+
     /// Authenticates the application making the request to the WorkOS server.
-    client_secret: &'a ApiKey,
+    client_secret: ApiKey,
 
     /// A string constant that distinguishes the method by which your application will receive an access token.
     grant_type: &'a str,
@@ -44,7 +66,8 @@ struct AuthenticateWithCodeBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with code](https://workos.com/docs/reference/user-management/authentication/code)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithCode {
     /// Authenticates a user using AuthKit, OAuth or an organization's SSO connection.
     ///
@@ -66,7 +89,7 @@ pub trait AuthenticateWithCode {
     /// let AuthenticationResponse { user, .. } = workos
     ///     .user_management()
     ///     .authenticate_with_code(&AuthenticateWithCodeParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         code_verifier: None,
     ///         code: &AuthorizationCode::from("01E2RJ4C05B52KKZ8FSRDAP23J"),
     ///         invitation_token: None,
@@ -83,8 +106,24 @@ pub trait AuthenticateWithCode {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithCode for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "authenticate_with_code",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/authenticate",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn authenticate_with_code(
         &self,
         params: &AuthenticateWithCodeParams<'_>,
@@ -95,6 +134,10 @@ impl AuthenticateWithCode for UserManagement<'_> {
             .join("/user_management/authenticate")?;
 
         let body = AuthenticateWithCodeBody {
+            client_id: self
+                .workos
+                .client_id_or_default(params.client_id)
+                .ok_or(WorkOsError::MissingClientId)?,
             client_secret: self.workos.key(),
             grant_type: "authorization_code",
             params,
@@ -107,16 +150,14 @@ impl AuthenticateWithCode for UserManagement<'_> {
             .json(&body)
             .send()
             .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
+            .handle_authenticate_error()?
+            .json::<AuthenticationResponse>()?;
 
         Ok(authenticate_with_code_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use mockito::Matcher;
@@ -136,7 +177,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -178,7 +220,7 @@ mod test {
         let response = workos
             .user_management()
             .authenticate_with_code(&AuthenticateWithCodeParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code_verifier: None,
                 code: &AuthorizationCode::from("abc123"),
                 invitation_token: None,
@@ -209,7 +251,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -227,7 +270,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_code(&AuthenticateWithCodeParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code_verifier: None,
                 code: &AuthorizationCode::from("abc123"),
                 invitation_token: None,
@@ -246,7 +289,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -264,7 +308,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_code(&AuthenticateWithCodeParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code_verifier: None,
                 code: &AuthorizationCode::from("abc123"),
                 invitation_token: None,
@@ -283,7 +327,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -301,7 +346,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_code(&AuthenticateWithCodeParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code_verifier: None,
                 code: &AuthorizationCode::from("abc123"),
                 invitation_token: None,
@@ -320,4 +365,23 @@ mod test {
             panic!("expected authenticate_with_code to return an error")
         }
     }
+
+    #[test]
+    fn it_redacts_the_secrets_from_the_debug_output() {
+        let params = AuthenticateWithCodeParams {
+            client_id: None,
+            code_verifier: Some("Rq5tGZkDXdHhTb9v4UJ5tL4WkTwAFRN1CVsCqkZcA7E"),
+            code: &AuthorizationCode::from("01E2RJ4C05B52KKZ8FSRDAP23J"),
+            invitation_token: Some("Z1uX3RbwcIl5fIGJJJCXXisdI"),
+            ip_address: None,
+            user_agent: Some("Mozilla/5.0"),
+        };
+
+        let debug = format!("{params:?}");
+
+        assert!(debug.contains("Mozilla/5.0"));
+        assert!(!debug.contains("Rq5tGZkDXdHhTb9v4UJ5tL4WkTwAFRN1CVsCqkZcA7E"));
+        assert!(!debug.contains("01E2RJ4C05B52KKZ8FSRDAP23J"));
+        assert!(!debug.contains("Z1uX3RbwcIl5fIGJJJCXXisdI"))
+    }
 }
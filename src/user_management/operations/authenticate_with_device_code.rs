@@ -13,7 +13,10 @@ use crate::{WorkOsError, WorkOsResult};
 #[derive(Debug, Serialize)]
 pub struct AuthenticateWithDeviceCodeParams<'a> {
     /// Identifies the application making the request to the WorkOS server.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    #[serde(skip)]
+    pub client_id: Option<&'a ClientId>,
 
     /// The device code obtained from the device authorization endpoint.
     pub device_code: &'a DeviceCode,
@@ -21,6 +24,9 @@ pub struct AuthenticateWithDeviceCodeParams<'a> {
 
 #[derive(Serialize)]
 struct AuthenticateWithDeviceCodeBody<'a> {
+    /// Identifies the application making the request to the WorkOS server.
+    client_id: &'a ClientId,
+
     /// A string constant that distinguishes the method by which your application will receive an access token.
     grant_type: &'a str,
 
@@ -79,7 +85,8 @@ impl IsUnauthorized for AuthenticateWithDeviceCodeError {
 }
 
 /// [WorkOS Docs: Authenticate with device code](https://workos.com/docs/reference/authkit/cli-auth/device-code)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithDeviceCode {
     /// Exchanges a device code for access and refresh tokens as part of the CLI Auth flow.
     ///
@@ -101,7 +108,7 @@ pub trait AuthenticateWithDeviceCode {
     /// let AuthenticationResponse { user, .. } = workos
     ///     .user_management()
     ///     .authenticate_with_device_code(&AuthenticateWithDeviceCodeParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         device_code: &DeviceCode::from("ETaHpDNhfxu0HyLhp6b8HGSh26NzYJSKw3TT6aS7HKKBhTyTD0zAW6ApTTolug0b"),
     ///     })
     ///     .await?;
@@ -114,8 +121,24 @@ pub trait AuthenticateWithDeviceCode {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateWithDeviceCodeError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithDeviceCode for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "authenticate_with_device_code",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/authenticate",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn authenticate_with_device_code(
         &self,
         params: &AuthenticateWithDeviceCodeParams<'_>,
@@ -126,6 +149,10 @@ impl AuthenticateWithDeviceCode for UserManagement<'_> {
             .join("/user_management/authenticate")?;
 
         let body = AuthenticateWithDeviceCodeBody {
+            client_id: self
+                .workos
+                .client_id_or_default(params.client_id)
+                .ok_or(WorkOsError::MissingClientId)?,
             grant_type: "urn:ietf:params:oauth:grant-type:device_code",
             params,
         };
@@ -137,16 +164,14 @@ impl AuthenticateWithDeviceCode for UserManagement<'_> {
             .json(&body)
             .send()
             .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
+            .handle_authenticate_error()?
+            .json::<AuthenticationResponse>()?;
 
         Ok(authenticate_with_device_code_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use mockito::Matcher;
@@ -166,7 +191,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -204,7 +230,7 @@ mod test {
         let response = workos
             .user_management()
             .authenticate_with_device_code(&AuthenticateWithDeviceCodeParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 device_code: &DeviceCode::from(
                     "ETaHpDNhfxu0HyLhp6b8HGSh26NzYJSKw3TT6aS7HKKBhTyTD0zAW6ApTTolug0b",
                 ),
@@ -235,7 +261,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -253,7 +280,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_device_code(&AuthenticateWithDeviceCodeParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 device_code: &DeviceCode::from(
                     "ETaHpDNhfxu0HyLhp6b8HGSh26NzYJSKw3TT6aS7HKKBhTyTD0zAW6ApTTolug0b",
                 ),
@@ -270,7 +297,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -288,7 +316,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_device_code(&AuthenticateWithDeviceCodeParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 device_code: &DeviceCode::from(
                     "ETaHpDNhfxu0HyLhp6b8HGSh26NzYJSKw3TT6aS7HKKBhTyTD0zAW6ApTTolug0b",
                 ),
@@ -305,7 +333,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -323,7 +352,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_device_code(&AuthenticateWithDeviceCodeParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 device_code: &DeviceCode::from(
                     "ETaHpDNhfxu0HyLhp6b8HGSh26NzYJSKw3TT6aS7HKKBhTyTD0zAW6ApTTolug0b",
                 ),
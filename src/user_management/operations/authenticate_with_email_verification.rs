@@ -1,3 +1,4 @@
+use std::fmt;
 use std::net::IpAddr;
 
 use async_trait::async_trait;
@@ -8,13 +9,18 @@ use crate::user_management::{
     AuthenticateError, AuthenticationResponse, EmailVerificationCode, HandleAuthenticateError,
     PendingAuthenticationToken, UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, Redacted, WorkOsError, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithEmailVerification`].
-#[derive(Debug, Serialize)]
+///
+/// The code is redacted from the [`Debug`] output.
+#[derive(Serialize)]
 pub struct AuthenticateWithEmailVerificationParams<'a> {
     /// Identifies the application making the request to the WorkOS server.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    #[serde(skip)]
+    pub client_id: Option<&'a ClientId>,
 
     /// The one-time email verification code received by the user.
     pub code: &'a EmailVerificationCode,
@@ -29,10 +35,28 @@ pub struct AuthenticateWithEmailVerificationParams<'a> {
     pub user_agent: Option<&'a str>,
 }
 
+impl fmt::Debug for AuthenticateWithEmailVerificationParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthenticateWithEmailVerificationParams")
+            .field("client_id", &self.client_id)
+            .field("code", &Redacted)
+            .field(
+                "pending_authentication_token",
+                &self.pending_authentication_token,
+            )
+            .field("ip_address", &self.ip_address)
+            .field("user_agent", &self.user_agent)
+            .finish()
+    }
+}
+
 #[derive(Serialize)]
 struct AuthenticateWithEmailVerificationBody<'a> {
+    /// Identifies the application making the request to the WorkOS server.
+    client_id: &'a ClientId,
+
     /// Authenticates the application making the request to the WorkOS server.
-    client_secret: &'a ApiKey,
+    client_secret: ApiKey,
 
     /// A string constant that distinguishes the method by which your application will receive an access token.
     grant_type: &'a str,
@@ -42,7 +66,8 @@ struct AuthenticateWithEmailVerificationBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with an email verification code](https://workos.com/docs/reference/user-management/authentication/email-verification)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithEmailVerification {
     /// Authenticates a user with an unverified email and verifies their email address.
     ///
@@ -64,7 +89,7 @@ pub trait AuthenticateWithEmailVerification {
     /// let AuthenticationResponse { user, .. } = workos
     ///     .user_management()
     ///     .authenticate_with_email_verification(&AuthenticateWithEmailVerificationParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         code: &EmailVerificationCode::from("123456"),
     ///         pending_authentication_token: &PendingAuthenticationToken::from("ql1AJgNoLN1tb9llaQ8jyC2dn"),
     ///         ip_address: Some(&IpAddr::from_str("192.0.2.1")?),
@@ -80,8 +105,24 @@ pub trait AuthenticateWithEmailVerification {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithEmailVerification for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "authenticate_with_email_verification",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/authenticate",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn authenticate_with_email_verification(
         &self,
         params: &AuthenticateWithEmailVerificationParams<'_>,
@@ -92,6 +133,10 @@ impl AuthenticateWithEmailVerification for UserManagement<'_> {
             .join("/user_management/authenticate")?;
 
         let body = AuthenticateWithEmailVerificationBody {
+            client_id: self
+                .workos
+                .client_id_or_default(params.client_id)
+                .ok_or(WorkOsError::MissingClientId)?,
             client_secret: self.workos.key(),
             grant_type: "urn:workos:oauth:grant-type:email-verification:code",
             params,
@@ -104,16 +149,14 @@ impl AuthenticateWithEmailVerification for UserManagement<'_> {
             .json(&body)
             .send()
             .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
+            .handle_authenticate_error()?
+            .json::<AuthenticationResponse>()?;
 
         Ok(authenticate_with_email_verification_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use mockito::Matcher;
@@ -133,7 +176,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -172,7 +216,7 @@ mod test {
         let response = workos
             .user_management()
             .authenticate_with_email_verification(&AuthenticateWithEmailVerificationParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: &EmailVerificationCode::from("123456"),
                 pending_authentication_token: &PendingAuthenticationToken::from(
                     "ql1AJgNoLN1tb9llaQ8jyC2dn",
@@ -204,7 +248,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -222,7 +267,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_email_verification(&AuthenticateWithEmailVerificationParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: &EmailVerificationCode::from("123456"),
                 pending_authentication_token: &PendingAuthenticationToken::from(
                     "ql1AJgNoLN1tb9llaQ8jyC2dn",
@@ -242,7 +287,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -260,7 +306,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_email_verification(&AuthenticateWithEmailVerificationParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: &EmailVerificationCode::from("123456"),
                 pending_authentication_token: &PendingAuthenticationToken::from(
                     "ql1AJgNoLN1tb9llaQ8jyC2dn",
@@ -280,7 +326,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -298,7 +345,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_email_verification(&AuthenticateWithEmailVerificationParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: &EmailVerificationCode::from("123456"),
                 pending_authentication_token: &PendingAuthenticationToken::from(
                     "ql1AJgNoLN1tb9llaQ8jyC2dn",
@@ -318,4 +365,23 @@ mod test {
             panic!("expected authenticate_with_magic_auth to return an error")
         }
     }
+
+    #[test]
+    fn it_redacts_the_secrets_from_the_debug_output() {
+        let params = AuthenticateWithEmailVerificationParams {
+            client_id: None,
+            code: &EmailVerificationCode::from("123456"),
+            pending_authentication_token: &PendingAuthenticationToken::from(
+                "ql1AJgNoLN1tb9llaQ8jyC2dn",
+            ),
+            ip_address: None,
+            user_agent: Some("Mozilla/5.0"),
+        };
+
+        let debug = format!("{params:?}");
+
+        assert!(debug.contains("Mozilla/5.0"));
+        assert!(!debug.contains("123456"));
+        assert!(!debug.contains("ql1AJgNoLN1tb9llaQ8jyC2dn"))
+    }
 }
@@ -1,3 +1,4 @@
+use std::fmt;
 use std::net::IpAddr;
 
 use async_trait::async_trait;
@@ -8,13 +9,18 @@ use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, MagicAuthCode,
     UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, Redacted, WorkOsError, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithMagicAuth`].
-#[derive(Debug, Serialize)]
+///
+/// The code and invitation token are redacted from the [`Debug`] output.
+#[derive(Serialize)]
 pub struct AuthenticateWithMagicAuthParams<'a> {
     /// Identifies the application making the request to the WorkOS server.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    #[serde(skip)]
+    pub client_id: Option<&'a ClientId>,
 
     /// The one-time code that was emailed to the user.
     pub code: &'a MagicAuthCode,
@@ -32,10 +38,26 @@ pub struct AuthenticateWithMagicAuthParams<'a> {
     pub user_agent: Option<&'a str>,
 }
 
+impl fmt::Debug for AuthenticateWithMagicAuthParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthenticateWithMagicAuthParams")
+            .field("client_id", &self.client_id)
+            .field("code", &Redacted)
+            .field("email", &self.email)
+            .field("invitation_token", &self.invitation_token.map(|_| Redacted))
+            .field("ip_address", &self.ip_address)
+            .field("user_agent", &self.user_agent)
+            .finish()
+    }
+}
+
 #[derive(Serialize)]
 struct AuthenticateWithMagicAuthBody<'a> {
+    /// Identifies the application making the request to the WorkOS server.
+    client_id: &'a ClientId,
+
     /// Authenticates the application making the request to the WorkOS server.
-    client_secret: &'a ApiKey,
+    client_secret: ApiKey,
 
     /// A string constant that distinguishes the method by which your application will receive an access token.
     grant_type: &'a str,
@@ -45,7 +67,8 @@ struct AuthenticateWithMagicAuthBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with Magic Auth](https://workos.com/docs/reference/user-management/authentication/magic-auth)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithMagicAuth {
     /// Authenticates a user by verifying the Magic Auth code sent to the user's email.
     ///
@@ -67,7 +90,7 @@ pub trait AuthenticateWithMagicAuth {
     /// let AuthenticationResponse { user, .. } = workos
     ///     .user_management()
     ///     .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         code: &MagicAuthCode::from("123456"),
     ///         email: "marcelina.davis@example.com",
     ///         invitation_token: None,
@@ -84,8 +107,24 @@ pub trait AuthenticateWithMagicAuth {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithMagicAuth for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "authenticate_with_magic_auth",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/authenticate",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn authenticate_with_magic_auth(
         &self,
         params: &AuthenticateWithMagicAuthParams<'_>,
@@ -96,6 +135,10 @@ impl AuthenticateWithMagicAuth for UserManagement<'_> {
             .join("/user_management/authenticate")?;
 
         let body = AuthenticateWithMagicAuthBody {
+            client_id: self
+                .workos
+                .client_id_or_default(params.client_id)
+                .ok_or(WorkOsError::MissingClientId)?,
             client_secret: self.workos.key(),
             grant_type: "urn:workos:oauth:grant-type:magic-auth:code",
             params,
@@ -108,16 +151,14 @@ impl AuthenticateWithMagicAuth for UserManagement<'_> {
             .json(&body)
             .send()
             .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
+            .handle_authenticate_error()?
+            .json::<AuthenticationResponse>()?;
 
         Ok(authenticate_with_magic_auth_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use mockito::Matcher;
@@ -137,7 +178,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -176,7 +218,7 @@ mod test {
         let response = workos
             .user_management()
             .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: &MagicAuthCode::from("123456"),
                 email: "marcelina.davis@example.com",
                 invitation_token: None,
@@ -207,7 +249,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -225,7 +268,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: &MagicAuthCode::from("123456"),
                 email: "marcelina.davis@example.com",
                 invitation_token: None,
@@ -244,7 +287,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -262,7 +306,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: &MagicAuthCode::from("123456"),
                 email: "marcelina.davis@example.com",
                 invitation_token: None,
@@ -281,7 +325,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -299,7 +344,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: &MagicAuthCode::from("123456"),
                 email: "marcelina.davis@example.com",
                 invitation_token: None,
@@ -318,4 +363,22 @@ mod test {
             panic!("expected authenticate_with_magic_auth to return an error")
         }
     }
+
+    #[test]
+    fn it_redacts_the_secrets_from_the_debug_output() {
+        let params = AuthenticateWithMagicAuthParams {
+            client_id: None,
+            code: &MagicAuthCode::from("123456"),
+            email: "marcelina@example.com",
+            invitation_token: Some("Z1uX3RbwcIl5fIGJJJCXXisdI"),
+            ip_address: None,
+            user_agent: None,
+        };
+
+        let debug = format!("{params:?}");
+
+        assert!(debug.contains("marcelina@example.com"));
+        assert!(!debug.contains("123456"));
+        assert!(!debug.contains("Z1uX3RbwcIl5fIGJJJCXXisdI"))
+    }
 }
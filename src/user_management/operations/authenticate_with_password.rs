@@ -1,3 +1,4 @@
+use std::fmt;
 use std::net::IpAddr;
 
 use async_trait::async_trait;
@@ -7,13 +8,18 @@ use crate::sso::ClientId;
 use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, Redacted, WorkOsError, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithPassword`].
-#[derive(Debug, Serialize)]
+///
+/// The password and invitation token are redacted from the [`Debug`] output.
+#[derive(Serialize)]
 pub struct AuthenticateWithPasswordParams<'a> {
     /// Identifies the application making the request to the WorkOS server.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    #[serde(skip)]
+    pub client_id: Option<&'a ClientId>,
 
     /// The email address of the user.
     pub email: &'a str,
@@ -31,10 +37,26 @@ pub struct AuthenticateWithPasswordParams<'a> {
     pub user_agent: Option<&'a str>,
 }
 
+impl fmt::Debug for AuthenticateWithPasswordParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthenticateWithPasswordParams")
+            .field("client_id", &self.client_id)
+            .field("email", &self.email)
+            .field("password", &Redacted)
+            .field("invitation_token", &self.invitation_token.map(|_| Redacted))
+            .field("ip_address", &self.ip_address)
+            .field("user_agent", &self.user_agent)
+            .finish()
+    }
+}
+
 #[derive(Serialize)]
 struct AuthenticateWithPasswordBody<'a> {
+    /// Identifies the application making the request to the WorkOS server.
+    client_id: &'a ClientId,
+
     /// Authenticates the application making the request to the WorkOS server.
-    client_secret: &'a ApiKey,
+    client_secret: ApiKey,
 
     /// A string constant that distinguishes the method by which your application will receive an access token.
     grant_type: &'a str,
@@ -44,7 +66,8 @@ struct AuthenticateWithPasswordBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with password](https://workos.com/docs/reference/user-management/authentication/password)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithPassword {
     /// Authenticates a user with email and password.
     ///
@@ -66,7 +89,7 @@ pub trait AuthenticateWithPassword {
     /// let AuthenticationResponse { user, .. } = workos
     ///     .user_management()
     ///     .authenticate_with_password(&AuthenticateWithPasswordParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         email: "marcelina@example.com",
     ///         password: "i8uv6g34kd490s",
     ///         invitation_token: None,
@@ -83,8 +106,24 @@ pub trait AuthenticateWithPassword {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithPassword for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "authenticate_with_password",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/authenticate",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn authenticate_with_password(
         &self,
         params: &AuthenticateWithPasswordParams<'_>,
@@ -95,6 +134,10 @@ impl AuthenticateWithPassword for UserManagement<'_> {
             .join("/user_management/authenticate")?;
 
         let body = AuthenticateWithPasswordBody {
+            client_id: self
+                .workos
+                .client_id_or_default(params.client_id)
+                .ok_or(WorkOsError::MissingClientId)?,
             client_secret: self.workos.key(),
             grant_type: "password",
             params,
@@ -107,16 +150,14 @@ impl AuthenticateWithPassword for UserManagement<'_> {
             .json(&body)
             .send()
             .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
+            .handle_authenticate_error()?
+            .json::<AuthenticationResponse>()?;
 
         Ok(authenticate_with_password_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use mockito::Matcher;
@@ -136,7 +177,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -175,7 +217,7 @@ mod test {
         let response = workos
             .user_management()
             .authenticate_with_password(&AuthenticateWithPasswordParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 email: "marcelina@example.com",
                 password: "i8uv6g34kd490s",
                 invitation_token: None,
@@ -199,6 +241,87 @@ mod test {
         )
     }
 
+    #[tokio::test]
+    async fn it_uses_the_default_client_id_of_the_client() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .client_id(&ClientId::from("client_123456789"))
+            .build()
+            .unwrap();
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::PartialJson(json!({
+                "client_id": "client_123456789",
+                "client_secret": "sk_example_123456789",
+                "grant_type": "password",
+            })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "user": {
+                        "object": "user",
+                        "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                        "email": "marcelina.davis@example.com",
+                        "first_name": "Marcelina",
+                        "last_name": "Davis",
+                        "email_verified": true,
+                        "profile_picture_url": "https://workoscdn.com/images/v1/123abc",
+                        "metadata": {},
+                        "created_at": "2021-06-25T19:07:33.155Z",
+                        "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+                    "access_token": "eyJhb.nNzb19vaWRjX2tleV9.lc5Uk4yWVk5In0",
+                    "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                    "authentication_method": "Password",
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .authenticate_with_password(&AuthenticateWithPasswordParams {
+                client_id: None,
+                email: "marcelina@example.com",
+                password: "i8uv6g34kd490s",
+                invitation_token: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.user.id,
+            UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_no_client_id_is_available() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let result = workos
+            .user_management()
+            .authenticate_with_password(&AuthenticateWithPasswordParams {
+                client_id: None,
+                email: "marcelina@example.com",
+                password: "i8uv6g34kd490s",
+                invitation_token: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await;
+
+        assert_matches!(result, Err(WorkOsError::MissingClientId))
+    }
+
     #[tokio::test]
     async fn it_returns_an_unauthorized_error_with_an_invalid_client() {
         let mut server = mockito::Server::new_async().await;
@@ -206,7 +329,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -224,7 +348,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_password(&AuthenticateWithPasswordParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 email: "marcelina@example.com",
                 password: "i8uv6g34kd490s",
                 invitation_token: None,
@@ -243,7 +367,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -261,7 +386,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_password(&AuthenticateWithPasswordParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 email: "marcelina@example.com",
                 password: "i8uv6g34kd490s",
                 invitation_token: None,
@@ -272,4 +397,120 @@ mod test {
 
         assert_matches!(result, Err(WorkOsError::Unauthorized))
     }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn it_records_a_span_without_secrets() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for Capture {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("{}={value:?}", field.name()));
+            }
+        }
+
+        impl<S: tracing::Subscriber> Layer<S> for Capture {
+            fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("span={}", attrs.metadata().name()));
+                attrs.record(&mut self.clone());
+            }
+
+            fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+                values.record(&mut self.clone());
+            }
+        }
+
+        let capture = Capture::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_header("X-Request-ID", "req_01H945H0YD4F97JN9MATX7BYAG")
+            .with_body(
+                json!({
+                    "error": "invalid_client",
+                    "error_description": "Invalid client ID."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _ = workos
+            .user_management()
+            .authenticate_with_password(&AuthenticateWithPasswordParams {
+                client_id: Some(&ClientId::from("client_123456789")),
+                email: "marcelina@example.com",
+                password: "i8uv6g34kd490s",
+                invitation_token: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await;
+
+        let records = capture.0.lock().unwrap();
+
+        for expected in [
+            "span=authenticate_with_password",
+            "http.request.method=\"POST\"",
+            "url.template=\"/user_management/authenticate\"",
+            "http.response.status_code=400",
+            "workos.request_id=\"req_01H945H0YD4F97JN9MATX7BYAG\"",
+        ] {
+            assert!(
+                records.iter().any(|record| record == expected),
+                "missing {expected} in {records:?}"
+            );
+        }
+
+        assert!(
+            records
+                .iter()
+                .any(|record| record.starts_with("latency_ms="))
+        );
+        assert!(records.iter().all(|record| {
+            !record.contains("sk_example_123456789") && !record.contains("i8uv6g34kd490s")
+        }))
+    }
+
+    #[test]
+    fn it_redacts_the_secrets_from_the_debug_output() {
+        let params = AuthenticateWithPasswordParams {
+            client_id: None,
+            email: "marcelina@example.com",
+            password: "i8uv6g34kd490s",
+            invitation_token: Some("Z1uX3RbwcIl5fIGJJJCXXisdI"),
+            ip_address: None,
+            user_agent: None,
+        };
+
+        let debug = format!("{params:?}");
+
+        assert!(debug.contains("marcelina@example.com"));
+        assert!(!debug.contains("i8uv6g34kd490s"));
+        assert!(!debug.contains("Z1uX3RbwcIl5fIGJJJCXXisdI"))
+    }
 }
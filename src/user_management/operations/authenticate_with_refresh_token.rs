@@ -9,13 +9,16 @@ use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, RefreshToken,
     UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, WorkOsError, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithRefreshToken`].
 #[derive(Debug, Serialize)]
 pub struct AuthenticateWithRefreshTokenParams<'a> {
     /// Identifies the application making the request to the WorkOS server.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    #[serde(skip)]
+    pub client_id: Option<&'a ClientId>,
 
     /// The refresh_token received from a successful authentication response.
     pub refresh_token: &'a RefreshToken,
@@ -32,8 +35,11 @@ pub struct AuthenticateWithRefreshTokenParams<'a> {
 
 #[derive(Serialize)]
 struct AuthenticateWithRefreshTokenBody<'a> {
+    /// Identifies the application making the request to the WorkOS server.
+    client_id: &'a ClientId,
+
     /// Authenticates the application making the request to the WorkOS server.
-    client_secret: &'a ApiKey,
+    client_secret: ApiKey,
 
     /// A string constant that distinguishes the method by which your application will receive an access token.
     grant_type: &'a str,
@@ -43,7 +49,8 @@ struct AuthenticateWithRefreshTokenBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with refresh token](https://workos.com/docs/reference/user-management/authentication/refresh-token)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithRefreshToken {
     /// Use this endpoint to exchange a refresh token for a new access token.
     ///
@@ -65,7 +72,7 @@ pub trait AuthenticateWithRefreshToken {
     /// let AuthenticationResponse { user, .. } = workos
     ///     .user_management()
     ///     .authenticate_with_refresh_token(&AuthenticateWithRefreshTokenParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         refresh_token: &RefreshToken::from("Xw0NsCVXMBf7svAoIoKBmkpEK"),
     ///         organization_id: None,
     ///         ip_address: Some(&IpAddr::from_str("192.0.2.1")?),
@@ -81,8 +88,24 @@ pub trait AuthenticateWithRefreshToken {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithRefreshToken for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "authenticate_with_refresh_token",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/authenticate",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn authenticate_with_refresh_token(
         &self,
         params: &AuthenticateWithRefreshTokenParams<'_>,
@@ -93,6 +116,10 @@ impl AuthenticateWithRefreshToken for UserManagement<'_> {
             .join("/user_management/authenticate")?;
 
         let body = AuthenticateWithRefreshTokenBody {
+            client_id: self
+                .workos
+                .client_id_or_default(params.client_id)
+                .ok_or(WorkOsError::MissingClientId)?,
             client_secret: self.workos.key(),
             grant_type: "refresh_token",
             params,
@@ -105,16 +132,14 @@ impl AuthenticateWithRefreshToken for UserManagement<'_> {
             .json(&body)
             .send()
             .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
+            .handle_authenticate_error()?
+            .json::<AuthenticationResponse>()?;
 
         Ok(authenticate_with_refresh_token_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use mockito::Matcher;
@@ -134,7 +159,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -176,7 +202,7 @@ mod test {
         let response = workos
             .user_management()
             .authenticate_with_refresh_token(&AuthenticateWithRefreshTokenParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 refresh_token: &RefreshToken::from("abc123"),
                 organization_id: None,
                 ip_address: None,
@@ -206,7 +232,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -224,7 +251,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_refresh_token(&AuthenticateWithRefreshTokenParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 refresh_token: &RefreshToken::from("abc123"),
                 organization_id: None,
                 ip_address: None,
@@ -242,7 +269,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -260,7 +288,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_refresh_token(&AuthenticateWithRefreshTokenParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 refresh_token: &RefreshToken::from("abc123"),
                 organization_id: None,
                 ip_address: None,
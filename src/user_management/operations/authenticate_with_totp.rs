@@ -1,3 +1,4 @@
+use std::fmt;
 use std::net::IpAddr;
 
 use async_trait::async_trait;
@@ -9,13 +10,18 @@ use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, PendingAuthenticationToken,
     UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, Redacted, WorkOsError, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithTotp`].
-#[derive(Debug, Serialize)]
+///
+/// The code is redacted from the [`Debug`] output.
+#[derive(Serialize)]
 pub struct AuthenticateWithTotpParams<'a> {
     /// Identifies the application making the request to the WorkOS server.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    #[serde(skip)]
+    pub client_id: Option<&'a ClientId>,
 
     /// The time-based one-time password generated by the factor that was challenged.
     pub code: &'a str,
@@ -33,10 +39,32 @@ pub struct AuthenticateWithTotpParams<'a> {
     pub user_agent: Option<&'a str>,
 }
 
+impl fmt::Debug for AuthenticateWithTotpParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthenticateWithTotpParams")
+            .field("client_id", &self.client_id)
+            .field("code", &Redacted)
+            .field(
+                "authentication_challenge_id",
+                &self.authentication_challenge_id,
+            )
+            .field(
+                "pending_authentication_token",
+                &self.pending_authentication_token,
+            )
+            .field("ip_address", &self.ip_address)
+            .field("user_agent", &self.user_agent)
+            .finish()
+    }
+}
+
 #[derive(Serialize)]
 struct AuthenticateWithTotpBody<'a> {
+    /// Identifies the application making the request to the WorkOS server.
+    client_id: &'a ClientId,
+
     /// Authenticates the application making the request to the WorkOS server.
-    client_secret: &'a ApiKey,
+    client_secret: ApiKey,
 
     /// A string constant that distinguishes the method by which your application will receive an access token.
     grant_type: &'a str,
@@ -46,7 +74,8 @@ struct AuthenticateWithTotpBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with a time-based one-time password](https://workos.com/docs/reference/user-management/authentication/totp)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithTotp {
     /// Authenticates a user enrolled into MFA using time-based one-time password (TOTP).
     ///
@@ -69,7 +98,7 @@ pub trait AuthenticateWithTotp {
     /// let AuthenticationResponse { user, .. } = workos
     ///     .user_management()
     ///     .authenticate_with_totp(&AuthenticateWithTotpParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         code: "123456",
     ///         authentication_challenge_id: &AuthenticationChallengeId::from("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5"),
     ///         pending_authentication_token: &PendingAuthenticationToken::from("ql1AJgNoLN1tb9llaQ8jyC2dn"),
@@ -86,8 +115,24 @@ pub trait AuthenticateWithTotp {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithTotp for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "authenticate_with_totp",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/authenticate",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn authenticate_with_totp(
         &self,
         params: &AuthenticateWithTotpParams<'_>,
@@ -98,6 +143,10 @@ impl AuthenticateWithTotp for UserManagement<'_> {
             .join("/user_management/authenticate")?;
 
         let body = AuthenticateWithTotpBody {
+            client_id: self
+                .workos
+                .client_id_or_default(params.client_id)
+                .ok_or(WorkOsError::MissingClientId)?,
             client_secret: self.workos.key(),
             grant_type: "urn:workos:oauth:grant-type:mfa-totp",
             params,
@@ -110,16 +159,14 @@ impl AuthenticateWithTotp for UserManagement<'_> {
             .json(&body)
             .send()
             .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
+            .handle_authenticate_error()?
+            .json::<AuthenticationResponse>()?;
 
         Ok(authenticate_with_totp_response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use matches::assert_matches;
     use mockito::Matcher;
@@ -139,7 +186,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -179,7 +227,7 @@ mod test {
         let response = workos
             .user_management()
             .authenticate_with_totp(&AuthenticateWithTotpParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: "123456",
                 authentication_challenge_id: &AuthenticationChallengeId::from(
                     "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
@@ -214,7 +262,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -232,7 +281,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_totp(&AuthenticateWithTotpParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: "123456",
                 authentication_challenge_id: &AuthenticationChallengeId::from(
                     "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
@@ -255,7 +304,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -273,7 +323,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_totp(&AuthenticateWithTotpParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: "123456",
                 authentication_challenge_id: &AuthenticationChallengeId::from(
                     "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
@@ -296,7 +346,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -314,7 +365,7 @@ mod test {
         let result = workos
             .user_management()
             .authenticate_with_totp(&AuthenticateWithTotpParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 code: "123456",
                 authentication_challenge_id: &AuthenticationChallengeId::from(
                     "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
@@ -337,4 +388,26 @@ mod test {
             panic!("expected authenticate_with_magic_auth to return an error")
         }
     }
+
+    #[test]
+    fn it_redacts_the_code_from_the_debug_output() {
+        let params = AuthenticateWithTotpParams {
+            client_id: None,
+            code: "123456",
+            authentication_challenge_id: &AuthenticationChallengeId::from(
+                "auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5",
+            ),
+            pending_authentication_token: &PendingAuthenticationToken::from(
+                "ql1AJgNoLN1tb9llaQ8jyC2dn",
+            ),
+            ip_address: None,
+            user_agent: None,
+        };
+
+        let debug = format!("{params:?}");
+
+        assert!(debug.contains("auth_challenge_01FVYZWQTZQ5VB6BC5MPG2EYC5"));
+        assert!(!debug.contains("123456"));
+        assert!(!debug.contains("ql1AJgNoLN1tb9llaQ8jyC2dn"))
+    }
 }
@@ -1,12 +1,16 @@
+use std::fmt;
+
 use async_trait::async_trait;
 use serde::Serialize;
 use thiserror::Error;
 
 use crate::user_management::{MagicAuth, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{Redacted, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreateMagicAuth`].
-#[derive(Debug, Serialize)]
+///
+/// The invitation token is redacted from the [`Debug`] output.
+#[derive(Serialize)]
 pub struct CreateMagicAuthParams<'a> {
     /// The email address of the user.
     pub email: &'a str,
@@ -15,6 +19,15 @@ pub struct CreateMagicAuthParams<'a> {
     pub invitation_token: Option<&'a str>,
 }
 
+impl fmt::Debug for CreateMagicAuthParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateMagicAuthParams")
+            .field("email", &self.email)
+            .field("invitation_token", &self.invitation_token.map(|_| Redacted))
+            .finish()
+    }
+}
+
 /// An error returned from [`CreateMagicAuth`].
 #[derive(Debug, Error)]
 pub enum CreateMagicAuthError {}
@@ -26,7 +39,8 @@ impl From<CreateMagicAuthError> for WorkOsError<CreateMagicAuthError> {
 }
 
 /// [WorkOS Docs: Create a Magic Auth code](https://workos.com/docs/reference/user-management/magic-auth/create)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait CreateMagicAuth {
     /// Creates a one-time authentication code that can be sent to the user's email address.
     ///
@@ -58,8 +72,24 @@ pub trait CreateMagicAuth {
     ) -> WorkOsResult<MagicAuth, CreateMagicAuthError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl CreateMagicAuth for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_magic_auth",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/magic_auth",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn create_magic_auth(
         &self,
         params: &CreateMagicAuthParams<'_>,
@@ -69,20 +99,18 @@ impl CreateMagicAuth for UserManagement<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<MagicAuth>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<MagicAuth>()?;
 
         Ok(magic_auth)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -99,7 +127,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/magic_auth")
@@ -134,4 +163,17 @@ mod test {
             MagicAuthId::from("magic_auth_01E4ZCR3C56J083X43JQXF3JK5")
         )
     }
+
+    #[test]
+    fn it_redacts_the_invitation_token_from_the_debug_output() {
+        let params = CreateMagicAuthParams {
+            email: "marcelina@example.com",
+            invitation_token: Some("Z1uX3RbwcIl5fIGJJJCXXisdI"),
+        };
+
+        let debug = format!("{params:?}");
+
+        assert!(debug.contains("marcelina@example.com"));
+        assert!(!debug.contains("Z1uX3RbwcIl5fIGJJJCXXisdI"))
+    }
 }
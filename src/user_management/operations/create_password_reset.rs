@@ -1,10 +1,10 @@
 use async_trait::async_trait;
-use reqwest::{Response, StatusCode};
+use http::StatusCode;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::user_management::{PasswordReset, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{HttpResponse, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreatePasswordReset`].
 #[derive(Debug, Serialize)]
@@ -34,37 +34,28 @@ impl From<CreatePasswordResetError> for WorkOsError<CreatePasswordResetError> {
     }
 }
 
-#[async_trait]
 pub(crate) trait HandleCreatePasswordResetError
 where
     Self: Sized,
 {
-    async fn handle_create_password_reset_error(
-        self,
-    ) -> WorkOsResult<Self, CreatePasswordResetError>;
+    fn handle_create_password_reset_error(self) -> WorkOsResult<Self, CreatePasswordResetError>;
 }
 
-#[async_trait]
-impl HandleCreatePasswordResetError for Response {
-    async fn handle_create_password_reset_error(
-        self,
-    ) -> WorkOsResult<Self, CreatePasswordResetError> {
-        match self.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => match err.status() {
-                Some(StatusCode::NOT_FOUND) => {
-                    let error = self.json::<CreatePasswordResetError>().await?;
-
-                    Err(WorkOsError::Operation(error))
-                }
-                _ => Err(WorkOsError::RequestError(err)),
-            },
+impl HandleCreatePasswordResetError for HttpResponse {
+    fn handle_create_password_reset_error(self) -> WorkOsResult<Self, CreatePasswordResetError> {
+        if self.status == StatusCode::NOT_FOUND {
+            let error = self.json::<CreatePasswordResetError>()?;
+
+            return Err(WorkOsError::Operation(error));
         }
+
+        self.handle_generic_error()
     }
 }
 
 /// [WorkOS Docs: Create a password reset token](https://workos.com/docs/reference/user-management/password-reset/create)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait CreatePasswordReset {
     /// Creates a one-time token that can be used to reset a user's password.
     ///
@@ -95,8 +86,24 @@ pub trait CreatePasswordReset {
     ) -> WorkOsResult<PasswordReset, CreatePasswordResetError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl CreatePasswordReset for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "create_password_reset",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/password_reset",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn create_password_reset(
         &self,
         params: &CreatePasswordResetParams<'_>,
@@ -109,21 +116,19 @@ impl CreatePasswordReset for UserManagement<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
             .handle_unauthorized_error()?
-            .handle_create_password_reset_error()
-            .await?
-            .json::<PasswordReset>()
-            .await?;
+            .handle_create_password_reset_error()?
+            .json::<PasswordReset>()?;
 
         Ok(password_reset)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -140,7 +145,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/password_reset")
@@ -15,7 +15,8 @@ impl From<DeleteOrganizationMembershipError> for WorkOsError<DeleteOrganizationM
 }
 
 /// [WorkOS Docs: Delete an organization membership](https://workos.com/docs/reference/user-management/organization-membership/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteOrganizationMembership {
     /// Permanently deletes an existing organization membership. It cannot be undone.
     ///
@@ -46,8 +47,24 @@ pub trait DeleteOrganizationMembership {
     ) -> WorkOsResult<(), DeleteOrganizationMembershipError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteOrganizationMembership for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "delete_organization_membership",
+            skip_all,
+            fields(
+                http.request.method = "DELETE",
+                url.template = "/user_management/users/{organization_membership_id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn delete_organization_membership(
         &self,
         organization_membership_id: &OrganizationMembershipId,
@@ -59,17 +76,16 @@ impl DeleteOrganizationMembership for UserManagement<'_> {
         self.workos
             .client()
             .delete(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?;
+            .handle_unauthorized_or_generic_error()?;
 
         Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use tokio;
 
@@ -84,7 +100,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
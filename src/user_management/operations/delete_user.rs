@@ -15,7 +15,8 @@ impl From<DeleteUserError> for WorkOsError<DeleteUserError> {
 }
 
 /// [WorkOS Docs: Delete a user](https://workos.com/docs/reference/user-management/user/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteUser {
     /// Permanently deletes a user in the current environment. It cannot be undone.
     ///
@@ -41,8 +42,24 @@ pub trait DeleteUser {
     async fn delete_user(&self, user_id: &UserId) -> WorkOsResult<(), DeleteUserError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteUser for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "delete_user",
+            skip_all,
+            fields(
+                http.request.method = "DELETE",
+                url.template = "/user_management/users/{user_id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn delete_user(&self, user_id: &UserId) -> WorkOsResult<(), DeleteUserError> {
         let url = self
             .workos
@@ -52,17 +69,16 @@ impl DeleteUser for UserManagement<'_> {
         self.workos
             .client()
             .delete(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?;
+            .handle_unauthorized_or_generic_error()?;
 
         Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use tokio;
 
@@ -77,7 +93,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
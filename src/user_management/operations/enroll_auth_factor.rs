@@ -1,11 +1,10 @@
 use async_trait::async_trait;
-use reqwest::{Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationFactor};
 use crate::user_management::{UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{HttpResponse, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`EnrollAuthFactor`].
 #[derive(Debug, Serialize)]
@@ -68,37 +67,23 @@ impl From<EnrollAuthFactorError> for WorkOsError<EnrollAuthFactorError> {
     }
 }
 
-#[async_trait]
 pub(crate) trait HandleEnrollAuthFactorError
 where
     Self: Sized,
 {
-    async fn handle_enroll_auth_factor_error(self) -> WorkOsResult<Self, EnrollAuthFactorError>;
+    fn handle_enroll_auth_factor_error(self) -> WorkOsResult<Self, EnrollAuthFactorError>;
 }
 
-#[async_trait]
-impl HandleEnrollAuthFactorError for Response {
-    async fn handle_enroll_auth_factor_error(self) -> WorkOsResult<Self, EnrollAuthFactorError> {
-        match self.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => match err.status() {
-                Some(StatusCode::BAD_REQUEST) | Some(StatusCode::UNPROCESSABLE_ENTITY) => {
-                    // let error = self.json::<EnrollAuthFactorError>().await?;
-                    let error = self.json::<serde_json::Value>().await?;
-
-                    println!("{error:#?}");
-
-                    // Err(WorkOsError::Operation(error))
-                    Err(WorkOsError::RequestError(err))
-                }
-                _ => Err(WorkOsError::RequestError(err)),
-            },
-        }
+impl HandleEnrollAuthFactorError for HttpResponse {
+    fn handle_enroll_auth_factor_error(self) -> WorkOsResult<Self, EnrollAuthFactorError> {
+        // TODO: Parse `400 Bad Request` and `422 Unprocessable Entity` responses into an `EnrollAuthFactorError`.
+        self.handle_generic_error()
     }
 }
 
 /// [WorkOS Docs: Enroll an authentication factor](https://workos.com/docs/reference/user-management/mfa/enroll-auth-factor)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait EnrollAuthFactor {
     /// Enrolls a user in a new authentication factor.
     ///
@@ -134,8 +119,24 @@ pub trait EnrollAuthFactor {
     ) -> WorkOsResult<EnrollAuthFactorResponse, EnrollAuthFactorError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl EnrollAuthFactor for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "enroll_auth_factor",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/users/{user_id}/auth_factors",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn enroll_auth_factor(
         &self,
         params: &EnrollAuthFactorParams<'_>,
@@ -149,21 +150,19 @@ impl EnrollAuthFactor for UserManagement<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
             .handle_unauthorized_error()?
-            .handle_enroll_auth_factor_error()
-            .await?
-            .json::<EnrollAuthFactorResponse>()
-            .await?;
+            .handle_enroll_auth_factor_error()?
+            .json::<EnrollAuthFactorResponse>()?;
 
         Ok(response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -180,7 +179,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/users/user_01FVYZ5QM8N98T9ME5BCB2BBMJ/auth_factors")
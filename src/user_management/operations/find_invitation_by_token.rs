@@ -15,7 +15,8 @@ impl From<FindInvitationByTokenError> for WorkOsError<FindInvitationByTokenError
 }
 
 /// [WorkOS Docs: Find an invitation by token](https://workos.com/docs/reference/user-management/invitation/find-by-token)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait FindInvitationByToken {
     /// Retrieve an existing invitation using the token.
     ///
@@ -44,33 +45,47 @@ pub trait FindInvitationByToken {
     ) -> WorkOsResult<Invitation, FindInvitationByTokenError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl FindInvitationByToken for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "find_invitation_by_token",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/invitations/by_token/{token}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn find_invitation_by_token(
         &self,
         token: &InvitationToken,
     ) -> WorkOsResult<Invitation, FindInvitationByTokenError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/user_management/invitations/by_token/{token}"))?;
+        let url = self.workos.base_url().join(&format!(
+            "/user_management/invitations/by_token/{}",
+            token.expose_secret()
+        ))?;
         let invitation = self
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Invitation>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<Invitation>()?;
 
         Ok(invitation)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -86,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -1,5 +1,6 @@
-use url::{ParseError, Url};
+use url::Url;
 
+use crate::BuildUrlError;
 use crate::organizations::OrganizationId;
 use crate::sso::{ClientId, ConnectionId};
 use crate::user_management::{OauthProvider, UserManagement};
@@ -51,7 +52,9 @@ pub enum ConnectionSelector<'a> {
 #[derive(Debug)]
 pub struct GetAuthorizationUrlParams<'a> {
     /// Identifies the application making the request to the WorkOS server.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    pub client_id: Option<&'a ClientId>,
 
     /// Where to redirect the user after they complete the authentication process.
     pub redirect_uri: &'a str,
@@ -83,18 +86,17 @@ pub trait GetAuthorizationUrl {
     /// # Examples
     ///
     /// ```
-    /// # use url::ParseError;
     /// # use workos::sso::{ClientId, ConnectionId};
     /// # use workos::user_management::*;
-    /// use workos::{ApiKey, WorkOs};
+    /// use workos::{ApiKey, BuildUrlError, WorkOs};
     ///
-    /// # fn run() -> Result<(), ParseError> {
+    /// # fn run() -> Result<(), BuildUrlError> {
     /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
     ///
     /// let authorization_url = workos
     ///     .user_management()
     ///     .get_authorization_url(&GetAuthorizationUrlParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///         redirect_uri: "https://your-app.com/callback",
     ///         connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
     ///             "conn_01E4ZCR3C56J083X43JQXF3JK5",
@@ -108,11 +110,17 @@ pub trait GetAuthorizationUrl {
     /// # }
     /// # run().unwrap();
     /// ```
-    fn get_authorization_url(&self, params: &GetAuthorizationUrlParams) -> Result<Url, ParseError>;
+    fn get_authorization_url(
+        &self,
+        params: &GetAuthorizationUrlParams,
+    ) -> Result<Url, BuildUrlError>;
 }
 
 impl GetAuthorizationUrl for UserManagement<'_> {
-    fn get_authorization_url(&self, params: &GetAuthorizationUrlParams) -> Result<Url, ParseError> {
+    fn get_authorization_url(
+        &self,
+        params: &GetAuthorizationUrlParams,
+    ) -> Result<Url, BuildUrlError> {
         let GetAuthorizationUrlParams {
             connection_selector,
             client_id,
@@ -124,7 +132,11 @@ impl GetAuthorizationUrl for UserManagement<'_> {
         } = params;
 
         let query = {
-            let client_id = client_id.to_string();
+            let client_id = self
+                .workos
+                .client_id_or_default(*client_id)
+                .ok_or(BuildUrlError::MissingClientId)?
+                .to_string();
 
             let connection_selector_param = match connection_selector {
                 ConnectionSelector::Connection(connection_id) => {
@@ -182,13 +194,14 @@ impl GetAuthorizationUrl for UserManagement<'_> {
             String::from(querystring::stringify(query_params).trim_end_matches('&'))
         };
 
-        self.workos
-            .base_url()
-            .join(&format!("/user_management/authorize?{query}"))
+        Ok(self
+            .workos
+            .auth_base_url()
+            .join(&format!("/user_management/authorize?{query}"))?)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use crate::{ApiKey, WorkOs};
 
@@ -201,7 +214,7 @@ mod test {
         let authorization_url = workos
             .user_management()
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 redirect_uri: "https://your-app.com/callback",
                 connection_selector: ConnectionSelector::Connection(&ConnectionId::from(
                     "conn_1234",
@@ -229,7 +242,7 @@ mod test {
         let authorization_url = workos
             .user_management()
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 redirect_uri: "https://your-app.com/callback",
                 connection_selector: ConnectionSelector::Organization(&OrganizationId::from(
                     "org_1234",
@@ -257,7 +270,7 @@ mod test {
         let authorization_url = workos
             .user_management()
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 redirect_uri: "https://your-app.com/callback",
                 connection_selector: ConnectionSelector::Provider(&Provider::Oauth(
                     OauthProvider::GoogleOAuth,
@@ -285,7 +298,7 @@ mod test {
         let authorization_url = workos
             .user_management()
             .get_authorization_url(&GetAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
                 redirect_uri: "https://your-app.com/callback",
                 connection_selector: ConnectionSelector::Provider(&Provider::AuthKit {
                     screen_hint: Some(ScreenHint::SignIn),
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use thiserror::Error;
 use url::Url;
 
@@ -8,10 +8,12 @@ use crate::user_management::{DeviceCode, UserManagement};
 use crate::{ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`GetDeviceAuthorizationUrl`].
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
 pub struct GetDeviceAuthorizationUrlParams<'a> {
     /// The WorkOS client ID for your application.
-    pub client_id: &'a ClientId,
+    ///
+    /// Defaults to the client ID of the [`WorkOs`](crate::WorkOs) client.
+    pub client_id: Option<&'a ClientId>,
 }
 
 /// The response for [`GetDeviceAuthorizationUrl`].
@@ -47,7 +49,8 @@ impl From<GetDeviceAuthorizationUrlError> for WorkOsError<GetDeviceAuthorization
 }
 
 /// [WorkOS Docs: Get device authorization URL](https://workos.com/docs/reference/user-management/cli-auth/device-authorization)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetDeviceAuthorizationUrl {
     /// Initiates the CLI Auth flow by requesting a device code and verification URLs.
     ///
@@ -67,7 +70,7 @@ pub trait GetDeviceAuthorizationUrl {
     /// let response = workos
     ///     .user_management()
     ///     .get_device_authorization_url(&GetDeviceAuthorizationUrlParams {
-    ///         client_id: &ClientId::from("client_123456789"),
+    ///         client_id: Some(&ClientId::from("client_123456789")),
     ///     })
     ///     .await?;
     /// # Ok(())
@@ -79,8 +82,24 @@ pub trait GetDeviceAuthorizationUrl {
     ) -> WorkOsResult<GetDeviceAuthorizationUrlResponse, GetDeviceAuthorizationUrlError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetDeviceAuthorizationUrl for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_device_authorization_url",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/authorize/device",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_device_authorization_url(
         &self,
         params: &GetDeviceAuthorizationUrlParams<'_>,
@@ -89,25 +108,27 @@ impl GetDeviceAuthorizationUrl for UserManagement<'_> {
             .workos
             .base_url()
             .join("/user_management/authorize/device")?;
+        let client_id = self
+            .workos
+            .client_id_or_default(params.client_id)
+            .ok_or(WorkOsError::MissingClientId)?;
 
         let response = self
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
-            .form(&params)
+            .bearer_auth(self.workos.key().expose_secret())
+            .form(&[("client_id", client_id)])
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<GetDeviceAuthorizationUrlResponse>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<GetDeviceAuthorizationUrlResponse>()?;
 
         Ok(response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -124,7 +145,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authorize/device")
@@ -148,7 +170,7 @@ mod test {
         let response = workos
             .user_management()
             .get_device_authorization_url(&GetDeviceAuthorizationUrlParams {
-                client_id: &ClientId::from("client_123456789"),
+                client_id: Some(&ClientId::from("client_123456789")),
             })
             .await
             .unwrap();
@@ -15,7 +15,8 @@ impl From<GetEmailVerificationError> for WorkOsError<GetEmailVerificationError>
 }
 
 /// [WorkOS Docs: Get an email verification code](https://workos.com/docs/reference/user-management/email-verification/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetEmailVerification {
     /// Get the details of an existing email verification code that can be used to send an email to a user for verification.
     ///
@@ -44,8 +45,24 @@ pub trait GetEmailVerification {
     ) -> WorkOsResult<EmailVerification, GetEmailVerificationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetEmailVerification for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_email_verification",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/email_verification/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_email_verification(
         &self,
         id: &EmailVerificationId,
@@ -58,19 +75,17 @@ impl GetEmailVerification for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<EmailVerification>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<EmailVerification>()?;
 
         Ok(email_verification)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -86,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -15,7 +15,8 @@ impl From<GetInvitationError> for WorkOsError<GetInvitationError> {
 }
 
 /// [WorkOS Docs: Get an invitation](https://workos.com/docs/reference/user-management/invitation/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetInvitation {
     /// Get the details of an existing invitation.
     ///
@@ -44,8 +45,24 @@ pub trait GetInvitation {
     ) -> WorkOsResult<Invitation, GetInvitationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetInvitation for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_invitation",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/invitations/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_invitation(
         &self,
         id: &InvitationId,
@@ -58,19 +75,17 @@ impl GetInvitation for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Invitation>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<Invitation>()?;
 
         Ok(invitation)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -86,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -13,10 +13,13 @@ use super::GetJwksUrl;
 pub enum GetJwksError {}
 
 /// [WorkOS Docs: Get JWKS](https://workos.com/docs/reference/user-management/session-tokens/jwks)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetJwks {
     /// Get JSON Web Key Set (JWKS).
     ///
+    /// Uses the client ID of the [`WorkOs`](crate::WorkOs) client if `client_id` is [`None`].
+    ///
     /// [WorkOS Docs: Get JWKS](https://workos.com/docs/reference/user-management/session-tokens/jwks)
     ///
     /// # Examples
@@ -32,17 +35,33 @@ pub trait GetJwks {
     ///
     /// let jwks = workos
     ///     .user_management()
-    ///     .get_jwks(&ClientId::from("client_123456789"))
+    ///     .get_jwks(Some(&ClientId::from("client_123456789")))
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_jwks(&self, client_id: &ClientId) -> WorkOsResult<JwkSet, GetJwksError>;
+    async fn get_jwks(&self, client_id: Option<&ClientId>) -> WorkOsResult<JwkSet, GetJwksError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetJwks for UserManagement<'_> {
-    async fn get_jwks(&self, client_id: &ClientId) -> WorkOsResult<JwkSet, GetJwksError> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_jwks",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/sso/jwks/{client_id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
+    async fn get_jwks(&self, client_id: Option<&ClientId>) -> WorkOsResult<JwkSet, GetJwksError> {
         let url = self.get_jwks_url(client_id)?;
 
         let jwks = self
@@ -51,23 +70,21 @@ impl GetJwks for UserManagement<'_> {
             .get(url)
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<JwkSet>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<JwkSet>()?;
 
         Ok(jwks)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
+    use http::StatusCode;
     use matches::assert_matches;
-    use reqwest::StatusCode;
     use serde_json::json;
     use tokio;
 
-    use crate::{ApiKey, JsonOrText, WorkOs, WorkOsError};
+    use crate::{ApiKey, WorkOs, WorkOsError};
 
     use super::*;
 
@@ -78,7 +95,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/sso/jwks/client_123456789")
@@ -94,7 +112,7 @@ mod test {
 
         let jwks = workos
             .user_management()
-            .get_jwks(&ClientId::from("client_123456789"))
+            .get_jwks(Some(&ClientId::from("client_123456789")))
             .await
             .unwrap();
 
@@ -108,7 +126,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/sso/jwks/client_123456789")
@@ -125,17 +144,13 @@ mod test {
 
         let result = workos
             .user_management()
-            .get_jwks(&ClientId::from("client_123456789"))
+            .get_jwks(Some(&ClientId::from("client_123456789")))
             .await;
 
         assert_matches!(
             result,
-            Err(WorkOsError::Unknown {
-                status: StatusCode::NOT_FOUND,
-                body: JsonOrText::Json(json)
-            }) if json == json!({
-                "message": "Not Found"
-            })
+            Err(WorkOsError::NotFound(error)) if error.status == StatusCode::NOT_FOUND
+                && error.message.as_deref() == Some("Not Found")
         )
     }
 }
@@ -1,5 +1,6 @@
-use url::{ParseError, Url};
+use url::Url;
 
+use crate::BuildUrlError;
 use crate::sso::ClientId;
 use crate::user_management::UserManagement;
 
@@ -7,33 +8,39 @@ use crate::user_management::UserManagement;
 pub trait GetJwksUrl {
     /// Returns a URL that hosts the JWKS for signing access tokens.
     ///
+    /// Uses the client ID of the [`WorkOs`](crate::WorkOs) client if `client_id` is [`None`].
+    ///
     /// [WorkOS Docs: Get JWKS URL](https://workos.com/docs/reference/user-management/session-tokens/jwks)
     ///
     /// # Examples
     ///
     /// ```
-    /// # use url::ParseError;
     /// # use workos::sso::ClientId;
     /// # use workos::user_management::*;
-    /// use workos::{ApiKey, WorkOs};
+    /// use workos::{ApiKey, BuildUrlError, WorkOs};
     ///
-    /// # fn run() -> Result<(), ParseError> {
+    /// # fn run() -> Result<(), BuildUrlError> {
     /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
     ///
     /// let jwks_url = workos
     ///     .user_management()
-    ///     .get_jwks_url(&ClientId::from("client_123456789"))?;
+    ///     .get_jwks_url(Some(&ClientId::from("client_123456789")))?;
     /// # Ok(())
     /// # }
     /// ```
-    fn get_jwks_url(&self, client_id: &ClientId) -> Result<Url, ParseError>;
+    fn get_jwks_url(&self, client_id: Option<&ClientId>) -> Result<Url, BuildUrlError>;
 }
 
 impl GetJwksUrl for UserManagement<'_> {
-    fn get_jwks_url(&self, client_id: &ClientId) -> Result<Url, ParseError> {
+    fn get_jwks_url(&self, client_id: Option<&ClientId>) -> Result<Url, BuildUrlError> {
+        let client_id = self
+            .workos
+            .client_id_or_default(client_id)
+            .ok_or(BuildUrlError::MissingClientId)?;
+
         let url = self
             .workos
-            .base_url()
+            .auth_base_url()
             .join("/sso/jwks/")?
             .join(&client_id.to_string())?;
 
@@ -41,8 +48,9 @@ impl GetJwksUrl for UserManagement<'_> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
+    use matches::assert_matches;
     use url::Url;
 
     use crate::sso::ClientId;
@@ -56,7 +64,7 @@ mod test {
 
         let jwks_url = workos
             .user_management()
-            .get_jwks_url(&ClientId::from("client_123456789"))
+            .get_jwks_url(Some(&ClientId::from("client_123456789")))
             .unwrap();
 
         assert_eq!(
@@ -64,4 +72,28 @@ mod test {
             Url::parse("https://api.workos.com/sso/jwks/client_123456789").unwrap()
         )
     }
+
+    #[test]
+    fn it_builds_a_jwks_url_with_the_default_client_id() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .client_id(&ClientId::from("client_123456789"))
+            .build()
+            .unwrap();
+
+        let jwks_url = workos.user_management().get_jwks_url(None).unwrap();
+
+        assert_eq!(
+            jwks_url,
+            Url::parse("https://api.workos.com/sso/jwks/client_123456789").unwrap()
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_no_client_id_is_available() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let result = workos.user_management().get_jwks_url(None);
+
+        assert_matches!(result, Err(BuildUrlError::MissingClientId))
+    }
 }
@@ -62,14 +62,14 @@ impl GetLogoutUrl for UserManagement<'_> {
 
         let url = self
             .workos
-            .base_url()
+            .auth_base_url()
             .join(&format!("/user_management/sessions/logout?{query}"))?;
 
         Ok(url)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use url::Url;
 
@@ -97,4 +97,27 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn it_builds_a_logout_url_on_the_auth_base_url() -> Result<(), ParseError> {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .auth_base_url("https://auth.your-app.com")?
+            .build()
+            .unwrap();
+
+        let logout_url = workos
+            .user_management()
+            .get_logout_url(&GetLogoutUrlParams {
+                session_id: &SessionId::from("session_01HQAG1HENBZMAZD82YRXDFC0B"),
+                return_to: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            logout_url,
+            Url::parse("https://auth.your-app.com/user_management/sessions/logout?session_id=session_01HQAG1HENBZMAZD82YRXDFC0B").unwrap()
+        );
+
+        Ok(())
+    }
 }
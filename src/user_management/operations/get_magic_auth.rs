@@ -15,7 +15,8 @@ impl From<GetMagicAuthError> for WorkOsError<GetMagicAuthError> {
 }
 
 /// [WorkOS Docs: Get a Magic Auth code](https://workos.com/docs/reference/user-management/magic-auth/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetMagicAuth {
     /// Get the details of an existing Magic Auth code that can be used to send an email to a user for authentication.
     ///
@@ -41,8 +42,24 @@ pub trait GetMagicAuth {
     async fn get_magic_auth(&self, id: &MagicAuthId) -> WorkOsResult<MagicAuth, GetMagicAuthError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetMagicAuth for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_magic_auth",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/magic_auth/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_magic_auth(&self, id: &MagicAuthId) -> WorkOsResult<MagicAuth, GetMagicAuthError> {
         let url = self
             .workos
@@ -52,19 +69,17 @@ impl GetMagicAuth for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<MagicAuth>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<MagicAuth>()?;
 
         Ok(magic_auth)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -80,7 +95,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
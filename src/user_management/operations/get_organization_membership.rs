@@ -15,7 +15,8 @@ impl From<GetOrganizationMembershipError> for WorkOsError<GetOrganizationMembers
 }
 
 /// [WorkOS Docs: Get an organization membership](https://workos.com/docs/reference/user-management/organization-membership/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetOrganizationMembership {
     /// Get the details of an existing organization membership.
     ///
@@ -44,8 +45,24 @@ pub trait GetOrganizationMembership {
     ) -> WorkOsResult<OrganizationMembership, GetOrganizationMembershipError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetOrganizationMembership for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_organization_membership",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/organization_memberships/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_organization_membership(
         &self,
         id: &OrganizationMembershipId,
@@ -58,19 +75,17 @@ impl GetOrganizationMembership for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<OrganizationMembership>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<OrganizationMembership>()?;
 
         Ok(organization_membership)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -86,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -15,7 +15,8 @@ impl From<GetPasswordResetError> for WorkOsError<GetPasswordResetError> {
 }
 
 /// [WorkOS Docs: Get a password reset token](https://workos.com/docs/reference/user-management/password-reset/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetPasswordReset {
     /// Get the details of an existing password reset token that can be used to reset a user's password.
     ///
@@ -44,8 +45,24 @@ pub trait GetPasswordReset {
     ) -> WorkOsResult<PasswordReset, GetPasswordResetError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetPasswordReset for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_password_reset",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/password_reset/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_password_reset(
         &self,
         id: &PasswordResetId,
@@ -58,19 +75,17 @@ impl GetPasswordReset for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PasswordReset>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<PasswordReset>()?;
 
         Ok(password_reset)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -86,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
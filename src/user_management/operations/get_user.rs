@@ -15,7 +15,8 @@ impl From<GetUserError> for WorkOsError<GetUserError> {
 }
 
 /// [WorkOS Docs: Get a user](https://workos.com/docs/reference/user-management/user/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetUser {
     /// Get the details of an existing user.
     ///
@@ -41,8 +42,24 @@ pub trait GetUser {
     async fn get_user(&self, id: &UserId) -> WorkOsResult<User, GetUserError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetUser for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_user",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/users/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_user(&self, id: &UserId) -> WorkOsResult<User, GetUserError> {
         let url = self
             .workos
@@ -52,19 +69,17 @@ impl GetUser for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<User>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<User>()?;
 
         Ok(user)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -80,7 +95,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -15,7 +15,8 @@ impl From<GetUserByExternalIdError> for WorkOsError<GetUserByExternalIdError> {
 }
 
 /// [WorkOS Docs: Get a user by external ID](https://workos.com/docs/reference/user-management/user/get-by-external-id)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetUserByExternalId {
     /// Get the details of an existing user by an external identifier.
     ///
@@ -44,8 +45,24 @@ pub trait GetUserByExternalId {
     ) -> WorkOsResult<User, GetUserByExternalIdError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetUserByExternalId for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_user_by_external_id",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/users/external_id/{external_id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_user_by_external_id(
         &self,
         external_id: &str,
@@ -61,19 +78,17 @@ impl GetUserByExternalId for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<User>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<User>()?;
 
         Ok(user)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -89,7 +104,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
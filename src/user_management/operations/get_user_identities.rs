@@ -15,7 +15,8 @@ impl From<GetUserIdentitiesError> for WorkOsError<GetUserIdentitiesError> {
 }
 
 /// [WorkOS Docs: Get User Identities](https://workos.com/docs/reference/user-management/identity/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetUserIdentities {
     /// Get a list of identities associated with the user.
     ///
@@ -44,8 +45,24 @@ pub trait GetUserIdentities {
     ) -> WorkOsResult<Vec<Identity>, GetUserIdentitiesError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetUserIdentities for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "get_user_identities",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/users/{user_id}/identities",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn get_user_identities(
         &self,
         user_id: &UserId,
@@ -59,19 +76,17 @@ impl GetUserIdentities for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Vec<Identity>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<Vec<Identity>>()?;
 
         Ok(identities)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -88,7 +103,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
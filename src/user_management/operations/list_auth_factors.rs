@@ -29,7 +29,8 @@ impl From<ListAuthFactorsError> for WorkOsError<ListAuthFactorsError> {
 }
 
 /// [WorkOS Docs: List authentication factors](https://workos.com/docs/reference/user-management/mfa/list-auth-factors)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListAuthFactors {
     /// Lists the authentication factors for a user.
     ///
@@ -61,8 +62,24 @@ pub trait ListAuthFactors {
     ) -> WorkOsResult<PaginatedList<AuthenticationFactor>, ()>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListAuthFactors for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "list_auth_factors",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/users/{user_id}/auth_factors",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn list_auth_factors(
         &self,
         params: &ListAuthFactorsParams<'_>,
@@ -77,19 +94,17 @@ impl ListAuthFactors for UserManagement<'_> {
             .client()
             .get(url)
             .query(&params)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<AuthenticationFactor>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<PaginatedList<AuthenticationFactor>>()?;
 
         Ok(auth_factors)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -106,7 +121,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/user_management/users/user_01FVYZ5QM8N98T9ME5BCB2BBMJ/auth_factors")
@@ -61,7 +61,8 @@ impl From<ListOrganizationMembershipsError> for WorkOsError<ListOrganizationMemb
 }
 
 /// [WorkOS Docs: List organization memberships](https://workos.com/docs/reference/user-management/organization_membership/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListOrganizationMemberships {
     /// Get a list of all organization memberships matching the criteria specified.
     ///
@@ -97,8 +98,24 @@ pub trait ListOrganizationMemberships {
     ) -> WorkOsResult<PaginatedList<OrganizationMembership>, ListOrganizationMembershipsError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListOrganizationMemberships for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "list_organization_memberships",
+            skip_all,
+            fields(
+                http.request.method = "GET",
+                url.template = "/user_management/organization_memberships",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn list_organization_memberships(
         &self,
         params: &ListOrganizationMembershipsParams,
@@ -113,19 +130,17 @@ impl ListOrganizationMemberships for UserManagement<'_> {
             .client()
             .get(url)
             .query(&params)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<OrganizationMembership>>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<PaginatedList<OrganizationMembership>>()?;
 
         Ok(organization_memberships)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -143,7 +158,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/user_management/organization_memberships")
@@ -210,7 +226,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/user_management/organization_memberships")
@@ -17,7 +17,8 @@ impl From<ReactivateOrganizationMembershipError>
 }
 
 /// [WorkOS Docs: Reactivate an organization membership](https://workos.com/docs/reference/user-management/organization-membership/reactivate)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ReactivateOrganizationMembership {
     /// Reactivates an `inactive` organization membership, retaining the pre-existing role.
     ///
@@ -48,8 +49,24 @@ pub trait ReactivateOrganizationMembership {
     ) -> WorkOsResult<OrganizationMembership, ReactivateOrganizationMembershipError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ReactivateOrganizationMembership for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "reactivate_organization_membership",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/organization_memberships/{organization_membership_id}/reactivate",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn reactivate_organization_membership(
         &self,
         organization_membership_id: &OrganizationMembershipId,
@@ -62,19 +79,17 @@ impl ReactivateOrganizationMembership for UserManagement<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<OrganizationMembership>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<OrganizationMembership>()?;
 
         Ok(organization_membership)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -91,7 +106,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
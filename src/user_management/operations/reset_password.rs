@@ -1,13 +1,17 @@
+use std::fmt;
+
 use async_trait::async_trait;
-use reqwest::{Response, StatusCode};
+use http::StatusCode;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::user_management::{PasswordResetToken, User, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{HttpResponse, Redacted, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`ResetPassword`].
-#[derive(Debug, Serialize)]
+///
+/// The token and new password are redacted from the [`Debug`] output.
+#[derive(Serialize)]
 pub struct ResetPasswordParams<'a> {
     /// The `token` query parameter from the password reset URL.
     pub token: &'a PasswordResetToken,
@@ -16,6 +20,15 @@ pub struct ResetPasswordParams<'a> {
     pub new_password: &'a str,
 }
 
+impl fmt::Debug for ResetPasswordParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResetPasswordParams")
+            .field("token", &Redacted)
+            .field("new_password", &Redacted)
+            .finish()
+    }
+}
+
 /// The response for [`ResetPassword`].
 #[derive(Debug, Deserialize)]
 pub struct ResetPasswordResponse {
@@ -76,33 +89,28 @@ pub enum PasswordResetError {
     },
 }
 
-#[async_trait]
 pub(crate) trait HandleResetPasswordError
 where
     Self: Sized,
 {
-    async fn handle_reset_password_error(self) -> WorkOsResult<Self, ResetPasswordError>;
+    fn handle_reset_password_error(self) -> WorkOsResult<Self, ResetPasswordError>;
 }
 
-#[async_trait]
-impl HandleResetPasswordError for Response {
-    async fn handle_reset_password_error(self) -> WorkOsResult<Self, ResetPasswordError> {
-        match self.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => match err.status() {
-                Some(StatusCode::BAD_REQUEST) | Some(StatusCode::NOT_FOUND) => {
-                    let error = self.json::<ResetPasswordError>().await?;
-
-                    Err(WorkOsError::Operation(error))
-                }
-                _ => Err(WorkOsError::RequestError(err)),
-            },
+impl HandleResetPasswordError for HttpResponse {
+    fn handle_reset_password_error(self) -> WorkOsResult<Self, ResetPasswordError> {
+        if matches!(self.status, StatusCode::BAD_REQUEST | StatusCode::NOT_FOUND) {
+            let error = self.json::<ResetPasswordError>()?;
+
+            return Err(WorkOsError::Operation(error));
         }
+
+        self.handle_generic_error()
     }
 }
 
 /// [WorkOS Docs: Reset the password](https://workos.com/docs/reference/user-management/password-reset/reset-password)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ResetPassword {
     /// Sets a new password using the token query parameter from the link that the user received.
     ///
@@ -134,8 +142,24 @@ pub trait ResetPassword {
     ) -> WorkOsResult<ResetPasswordResponse, ResetPasswordError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ResetPassword for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "reset_password",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/password_reset/confirm",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn reset_password(
         &self,
         params: &ResetPasswordParams<'_>,
@@ -149,21 +173,19 @@ impl ResetPassword for UserManagement<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
             .handle_unauthorized_error()?
-            .handle_reset_password_error()
-            .await?
-            .json::<ResetPasswordResponse>()
-            .await?;
+            .handle_reset_password_error()?
+            .json::<ResetPasswordResponse>()?;
 
         Ok(response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -180,7 +202,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/password_reset/confirm")
@@ -220,4 +243,17 @@ mod test {
             UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
         )
     }
+
+    #[test]
+    fn it_redacts_the_secrets_from_the_debug_output() {
+        let params = ResetPasswordParams {
+            token: &PasswordResetToken::from("stpIJ48IFJt0HhSIqjf8eppe0"),
+            new_password: "i8uv6g34kd490s",
+        };
+
+        assert_eq!(
+            format!("{params:?}"),
+            "ResetPasswordParams { token: [REDACTED], new_password: [REDACTED] }"
+        )
+    }
 }
@@ -15,7 +15,8 @@ impl From<RevokeInvitationError> for WorkOsError<RevokeInvitationError> {
 }
 
 /// [WorkOS Docs: Revoke an invitation](https://workos.com/docs/reference/user-management/invitation/revoke)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait RevokeInvitation {
     /// Revokes an existing invitation.
     ///
@@ -44,8 +45,24 @@ pub trait RevokeInvitation {
     ) -> WorkOsResult<Invitation, RevokeInvitationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl RevokeInvitation for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "revoke_invitation",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/user_management/invitations/{invitation_id}/revoke",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn revoke_invitation(
         &self,
         invitation_id: &InvitationId,
@@ -57,19 +74,17 @@ impl RevokeInvitation for UserManagement<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<Invitation>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<Invitation>()?;
 
         Ok(invitation)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -86,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/invitations/invitation_01E4ZCR3C56J083X43JQXF3JK5/revoke")
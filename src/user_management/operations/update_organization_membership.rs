@@ -28,7 +28,8 @@ impl From<UpdateOrganizationMembershipError> for WorkOsError<UpdateOrganizationM
 }
 
 /// [WorkOS Docs: Update an organization membership](https://workos.com/docs/reference/user-management/organization-membership/update)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait UpdateOrganizationMembership {
     /// Update the details of an existing organization membership.
     ///
@@ -61,8 +62,24 @@ pub trait UpdateOrganizationMembership {
     ) -> WorkOsResult<OrganizationMembership, UpdateOrganizationMembershipError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl UpdateOrganizationMembership for UserManagement<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "update_organization_membership",
+            skip_all,
+            fields(
+                http.request.method = "PUT",
+                url.template = "/user_management/organization_memberships/{id}",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn update_organization_membership(
         &self,
         params: &UpdateOrganizationMembershipParams<'_>,
@@ -75,20 +92,18 @@ impl UpdateOrganizationMembership for UserManagement<'_> {
             .workos
             .client()
             .put(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<OrganizationMembership>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<OrganizationMembership>()?;
 
         Ok(organization_membership)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use serde_json::json;
     use tokio;
@@ -105,7 +120,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -1,10 +1,9 @@
-use async_trait::async_trait;
-use reqwest::{Response, StatusCode};
+use http::StatusCode;
 use serde::{Deserialize, de::DeserializeOwned};
 use thiserror::Error;
 
 use crate::{
-    WorkOsError, WorkOsResult, mfa::AuthenticationFactorIdAndType,
+    HttpResponse, ResponseExt, WorkOsError, WorkOsResult, mfa::AuthenticationFactorIdAndType,
     organizations::OrganizationIdAndName, sso::ConnectionId,
 };
 
@@ -289,44 +288,29 @@ impl AuthenticateErrorWithError {
     }
 }
 
-#[async_trait]
 pub(crate) trait HandleAuthenticateError<E>
 where
     Self: Sized,
     E: DeserializeOwned + IsUnauthorized,
 {
-    async fn handle_authenticate_error(self) -> WorkOsResult<Self, E>;
+    fn handle_authenticate_error(self) -> WorkOsResult<Self, E>;
 }
 
-#[async_trait]
-impl<E> HandleAuthenticateError<E> for Response
+impl<E> HandleAuthenticateError<E> for HttpResponse
 where
     E: DeserializeOwned + IsUnauthorized,
 {
-    async fn handle_authenticate_error(self) -> WorkOsResult<Self, E> {
-        match self.error_for_status_ref() {
-            Ok(_) => Ok(self),
-            Err(err) => match err.status() {
-                Some(StatusCode::BAD_REQUEST) => {
-                    let authenticate_error = self.json::<E>().await?;
-
-                    Err(if authenticate_error.is_unauthorized() {
-                        WorkOsError::Unauthorized
-                    } else {
-                        WorkOsError::Operation(authenticate_error)
-                    })
-                }
-                Some(StatusCode::FORBIDDEN) => {
-                    let authenticate_error = self.json::<E>().await?;
-
-                    Err(if authenticate_error.is_unauthorized() {
-                        WorkOsError::Unauthorized
-                    } else {
-                        WorkOsError::Operation(authenticate_error)
-                    })
-                }
-                _ => Err(WorkOsError::RequestError(err)),
-            },
+    fn handle_authenticate_error(self) -> WorkOsResult<Self, E> {
+        if matches!(self.status, StatusCode::BAD_REQUEST | StatusCode::FORBIDDEN) {
+            let authenticate_error = self.json::<E>()?;
+
+            return Err(if authenticate_error.is_unauthorized() {
+                WorkOsError::Unauthorized
+            } else {
+                WorkOsError::Operation(authenticate_error)
+            });
         }
+
+        self.handle_generic_error()
     }
 }
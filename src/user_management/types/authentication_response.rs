@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{organizations::OrganizationId, sso::AccessToken};
+use crate::{KnownOrUnknown, organizations::OrganizationId, sso::AccessToken};
 
 use super::{Impersonator, RefreshToken, User};
 
@@ -51,8 +51,39 @@ pub struct AuthenticationResponse {
     pub refresh_token: RefreshToken,
 
     /// The authentication method used to initiate the session.
-    pub authentication_method: AuthenticationMethod,
+    pub authentication_method: KnownOrUnknown<AuthenticationMethod, String>,
 
     /// The WorkOS Dashboard user who is impersonating the user.
     pub impersonator: Option<Impersonator>,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::KnownOrUnknown;
+
+    use super::AuthenticationMethod;
+
+    #[test]
+    fn it_deserializes_known_authentication_methods() {
+        assert_eq!(
+            serde_json::from_value::<KnownOrUnknown<AuthenticationMethod, String>>(json!(
+                "GoogleOAuth"
+            ))
+            .unwrap(),
+            KnownOrUnknown::Known(AuthenticationMethod::GoogleOAuth)
+        )
+    }
+
+    #[test]
+    fn it_deserializes_unknown_authentication_methods() {
+        assert_eq!(
+            serde_json::from_value::<KnownOrUnknown<AuthenticationMethod, String>>(json!(
+                "CrossAppAuth"
+            ))
+            .unwrap(),
+            KnownOrUnknown::Unknown("CrossAppAuth".to_string())
+        )
+    }
+}
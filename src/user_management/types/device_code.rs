@@ -1,9 +1,16 @@
-use derive_more::{Deref, Display, From};
+use derive_more::From;
 use serde::{Deserialize, Serialize};
 
+use crate::SecretString;
+
 /// A device code that may be exchanged for an access token.
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct DeviceCode(String);
+pub struct DeviceCode(SecretString);
+
+impl DeviceCode {
+    /// Returns the value of the device code.
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
@@ -1,6 +1,8 @@
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
+use crate::KnownOrUnknown;
+
 use super::OauthProvider;
 
 /// The ID of a [`Identity`].
@@ -11,13 +13,20 @@ use super::OauthProvider;
 pub struct IdentityId(String);
 
 /// The type of the identity.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum IdentityType {
     /// OAuth identity.
     OAuth {
         /// The type of OAuth provider for the identity.
-        provider: OauthProvider,
+        provider: KnownOrUnknown<OauthProvider, String>,
+    },
+
+    /// A type of identity that is not known to this version of the SDK.
+    #[serde(untagged)]
+    Unknown {
+        /// The type of the identity.
+        r#type: String,
     },
 }
 
@@ -31,3 +40,33 @@ pub struct Identity {
     #[serde(flatten)]
     pub r#type: IdentityType,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::KnownOrUnknown;
+
+    use super::IdentityType;
+
+    #[test]
+    fn it_deserializes_unknown_oauth_providers() {
+        assert!(matches!(
+            serde_json::from_value::<IdentityType>(json!({
+                "type": "OAuth",
+                "provider": "SlackOAuth"
+            }))
+            .unwrap(),
+            IdentityType::OAuth { provider }
+                if provider == KnownOrUnknown::Unknown("SlackOAuth".to_string())
+        ))
+    }
+
+    #[test]
+    fn it_deserializes_unknown_identity_types() {
+        assert!(matches!(
+            serde_json::from_value::<IdentityType>(json!({ "type": "Passkey" })).unwrap(),
+            IdentityType::Unknown { r#type } if r#type == "Passkey"
+        ))
+    }
+}
@@ -1,10 +1,12 @@
+use std::fmt;
+
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::organizations::OrganizationId;
 use crate::user_management::UserId;
-use crate::{KnownOrUnknown, Timestamp, Timestamps};
+use crate::{KnownOrUnknown, Redacted, SecretString, Timestamp, Timestamps};
 
 /// The ID of an [`Invitation`].
 #[derive(
@@ -31,14 +33,22 @@ pub enum InvitationState {
 }
 
 /// The token of an [`Invitation`].
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct InvitationToken(String);
+pub struct InvitationToken(SecretString);
+
+impl InvitationToken {
+    /// Returns the value of the invitation token.
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
 
 /// [WorkOS Docs: Invitation](https://workos.com/docs/reference/user-management/invitation)
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The URL used to accept the invitation, which contains the token, is redacted from the [`Debug`]
+/// output.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Invitation {
     /// The unique ID of the invitation.
     pub id: InvitationId,
@@ -78,6 +88,25 @@ pub struct Invitation {
     pub timestamps: Timestamps,
 }
 
+impl fmt::Debug for Invitation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Invitation")
+            .field("id", &self.id)
+            .field("email", &self.email)
+            .field("state", &self.state)
+            .field("accepted_at", &self.accepted_at)
+            .field("revoked_at", &self.revoked_at)
+            .field("expires_at", &self.expires_at)
+            .field("token", &self.token)
+            .field("accept_invitation_url", &Redacted)
+            .field("organization_id", &self.organization_id)
+            .field("inviter_user_id", &self.inviter_user_id)
+            .field("accepted_user_id", &self.accepted_user_id)
+            .field("timestamps", &self.timestamps)
+            .finish()
+    }
+}
+
 /// [WorkOS Docs: Invitation events](https://workos.com/docs/events/invitation)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InvitationEvent {
@@ -112,3 +141,36 @@ pub struct InvitationEvent {
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_redacts_the_token_from_the_debug_output() {
+        let invitation: Invitation = serde_json::from_value(json!({
+            "object": "invitation",
+            "id": "invitation_01E4ZCR3C56J083X43JQXF3JK5",
+            "email": "marcelina.davis@example.com",
+            "state": "pending",
+            "accepted_at": null,
+            "revoked_at": null,
+            "expires_at": "2021-07-01T19:07:33.155Z",
+            "token": "Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "accept_invitation_url": "https://your-app.com/invite?invitation_token=Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "organization_id": null,
+            "inviter_user_id": null,
+            "accepted_user_id": null,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        }))
+        .unwrap();
+
+        let debug = format!("{invitation:?}");
+
+        assert!(debug.contains("marcelina.davis@example.com"));
+        assert!(!debug.contains("Z1uX3RbwcIl5fIGJJJCXXisdI"))
+    }
+}
@@ -1,5 +1,9 @@
+use std::fmt;
+
 use serde::Serialize;
 
+use crate::Redacted;
+
 /// The algorithm used to hash a password.
 #[derive(Clone, Copy, Debug, Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -21,7 +25,9 @@ pub enum PasswordHashType {
 }
 
 /// Password to set for the user.
-#[derive(Debug, Serialize)]
+///
+/// The password and password hash are redacted from the [`Debug`] output.
+#[derive(Serialize)]
 #[serde(untagged)]
 pub enum PasswordParams<'a> {
     /// Plain text password.
@@ -38,3 +44,45 @@ pub enum PasswordParams<'a> {
         password_hash_type: PasswordHashType,
     },
 }
+
+impl fmt::Debug for PasswordParams<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Password { .. } => f
+                .debug_struct("Password")
+                .field("password", &Redacted)
+                .finish(),
+            Self::PasswordHash {
+                password_hash_type, ..
+            } => f
+                .debug_struct("PasswordHash")
+                .field("password_hash", &Redacted)
+                .field("password_hash_type", password_hash_type)
+                .finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_redacts_the_password_from_the_debug_output() {
+        let password = PasswordParams::Password {
+            password: "i8uv6g34kd490s",
+        };
+
+        assert_eq!(format!("{password:?}"), "Password { password: [REDACTED] }");
+
+        let password_hash = PasswordParams::PasswordHash {
+            password_hash: "$2b$10$//DXiVVE59p7G5k/4Klx/ezF7BI42QZKmoOD0NDvUuqxRE5bFFBLy",
+            password_hash_type: PasswordHashType::Bcrypt,
+        };
+
+        assert_eq!(
+            format!("{password_hash:?}"),
+            "PasswordHash { password_hash: [REDACTED], password_hash_type: Bcrypt }"
+        )
+    }
+}
@@ -1,8 +1,10 @@
+use std::fmt;
+
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::Timestamp;
+use crate::{Redacted, SecretString, Timestamp};
 
 use super::UserId;
 
@@ -14,14 +16,21 @@ use super::UserId;
 pub struct PasswordResetId(String);
 
 /// The one-time token that can be used to reset a user's password.
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct PasswordResetToken(String);
+pub struct PasswordResetToken(SecretString);
+
+impl PasswordResetToken {
+    /// Returns the value of the password reset token.
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
 
 /// [WorkOS Docs: Password Reset](https://workos.com/docs/reference/user-management/password-reset)
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The password reset URL, which contains the token, is redacted from the [`Debug`] output.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PasswordReset {
     /// The unique ID of the password reset token.
     pub id: PasswordResetId,
@@ -45,6 +54,20 @@ pub struct PasswordReset {
     pub created_at: Timestamp,
 }
 
+impl fmt::Debug for PasswordReset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PasswordReset")
+            .field("id", &self.id)
+            .field("user_id", &self.user_id)
+            .field("email", &self.email)
+            .field("password_reset_token", &self.password_reset_token)
+            .field("password_reset_url", &Redacted)
+            .field("expires_at", &self.expires_at)
+            .field("created_at", &self.created_at)
+            .finish()
+    }
+}
+
 /// [WorkOS Docs: Password reset events](https://workos.com/docs/events/password-reset)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PasswordResetEvent {
@@ -63,3 +86,34 @@ pub struct PasswordResetEvent {
     /// The timestamp indicating when the object was created.
     pub created_at: Timestamp,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_redacts_the_token_from_the_debug_output() {
+        let password_reset: PasswordReset = serde_json::from_value(json!({
+            "object": "password_reset",
+            "id": "password_reset_01HYGDNK5G7FZ4YJFXYXPB5JRW",
+            "user_id": "user_01HWWYEH2NPEWGMMGF8C1ND0YT",
+            "email": "marcelina.davis@example.com",
+            "password_reset_token": "Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "password_reset_url": "https://your-app.com/reset-password?token=Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "expires_at": "2021-07-01T19:07:33.155Z",
+            "created_at": "2021-06-25T19:07:33.155Z"
+        }))
+        .unwrap();
+
+        let debug = format!("{password_reset:?}");
+
+        assert!(debug.contains("marcelina.davis@example.com"));
+        assert!(!debug.contains("Z1uX3RbwcIl5fIGJJJCXXisdI"));
+        assert_eq!(
+            password_reset.password_reset_token.expose_secret(),
+            "Z1uX3RbwcIl5fIGJJJCXXisdI"
+        )
+    }
+}
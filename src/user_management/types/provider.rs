@@ -2,7 +2,7 @@ use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
 /// The type of OAuth provider.
-#[derive(Clone, Copy, Debug, Display, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OauthProvider {
     /// Apple OAuth.
     AppleOAuth,
@@ -1,9 +1,16 @@
-use derive_more::{Deref, Display, From};
+use derive_more::From;
 use serde::{Deserialize, Serialize};
 
+use crate::SecretString;
+
 /// A refresh token that may be exchanged for a new [`AccessToken`](crate::sso::AccessToken).
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct RefreshToken(String);
+pub struct RefreshToken(SecretString);
+
+impl RefreshToken {
+    /// Returns the value of the refresh token.
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
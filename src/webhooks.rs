@@ -0,0 +1,19 @@
+//! A module for verifying and parsing the webhooks sent by WorkOS.
+//!
+//! Verifying a webhook does not require a [`WorkOs`](crate::WorkOs) client, only the secret of the
+//! webhook endpoint, which is available in the WorkOS Dashboard.
+//!
+//! With the `axum` or `actix-web` feature, the [`VerifiedEvent`] extractor verifies webhook requests
+//! before they reach the handler, rejecting them with `400 Bad Request` or `401 Unauthorized`.
+//!
+//! [WorkOS Docs: Webhooks Guide](https://workos.com/docs/events/data-syncing/webhooks)
+
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+mod extract;
+mod operations;
+mod types;
+
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+pub use extract::*;
+pub use operations::*;
+pub use types::*;
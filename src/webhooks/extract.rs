@@ -0,0 +1,128 @@
+#[cfg(feature = "actix-web")]
+mod actix_web;
+#[cfg(feature = "axum")]
+mod axum;
+
+use std::time::Duration;
+
+use http::StatusCode;
+use thiserror::Error;
+
+use crate::events::Event;
+use crate::webhooks::{ConstructEventError, ConstructEventParams, WebhookSecret, construct_event};
+
+/// The configuration used by [`VerifiedEvent`] to verify webhooks.
+///
+/// With the `axum` feature, the state of the router must provide it through
+/// [`FromRef`](::axum::extract::FromRef). With the `actix-web` feature, it must be registered as
+/// [`Data`](::actix_web::web::Data) of the app.
+#[derive(Clone, Debug)]
+pub struct WebhookConfig {
+    /// The secret of the webhook endpoint.
+    pub secret: WebhookSecret,
+
+    /// The maximum difference between the time the webhook was signed and the current time.
+    ///
+    /// Defaults to [`DEFAULT_WEBHOOK_TOLERANCE`](crate::webhooks::DEFAULT_WEBHOOK_TOLERANCE).
+    pub tolerance: Option<Duration>,
+}
+
+/// An extractor for the [`Event`] of a webhook request whose signature has been verified.
+///
+/// Requests without a valid signature are rejected with a [`WebhookRejection`].
+///
+/// # Examples
+///
+/// The extractor can be used in the handlers of both axum and Actix Web, given that a
+/// [`WebhookConfig`] has been provided to the router or app.
+///
+/// ```
+/// use workos::events::EventData;
+/// use workos::webhooks::VerifiedEvent;
+///
+/// async fn handle_webhook(VerifiedEvent(event): VerifiedEvent) {
+///     if let EventData::UserCreated(user_created) = event.data {
+///         println!("{}", user_created.0.email);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct VerifiedEvent(pub Event);
+
+/// An error returned when a webhook request is rejected by [`VerifiedEvent`].
+#[derive(Debug, Error)]
+pub enum WebhookRejection {
+    /// The request has no valid [`WorkOS-Signature`](crate::webhooks::WEBHOOK_SIGNATURE_HEADER)
+    /// header.
+    #[error("missing webhook signature header")]
+    MissingSignature,
+
+    /// The body of the request could not be read.
+    #[error("failed to read webhook body: {0}")]
+    InvalidBody(String),
+
+    /// The webhook could not be verified or parsed.
+    #[error(transparent)]
+    ConstructEvent(#[from] ConstructEventError),
+
+    /// No [`WebhookConfig`] is available to verify the webhook.
+    #[error("missing webhook configuration")]
+    MissingConfig,
+}
+
+impl WebhookRejection {
+    /// Returns the status code of the response to the rejected request.
+    ///
+    /// Malformed requests are rejected with `400 Bad Request`, requests whose signature does not
+    /// match or has expired with `401 Unauthorized`, and signed requests whose payload is not a
+    /// valid event with `422 Unprocessable Entity`.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            WebhookRejection::MissingSignature
+            | WebhookRejection::InvalidBody(_)
+            | WebhookRejection::ConstructEvent(ConstructEventError::InvalidSignatureHeader(_)) => {
+                StatusCode::BAD_REQUEST
+            }
+            WebhookRejection::ConstructEvent(
+                ConstructEventError::SignatureMismatch
+                | ConstructEventError::TimestampOutsideTolerance,
+            ) => StatusCode::UNAUTHORIZED,
+            WebhookRejection::ConstructEvent(ConstructEventError::InvalidPayload(_)) => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            WebhookRejection::MissingConfig => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Verifies the signature of the webhook request and returns its event.
+fn verify(
+    config: &WebhookConfig,
+    signature: Option<&str>,
+    body: &[u8],
+) -> Result<VerifiedEvent, WebhookRejection> {
+    let signature = signature.ok_or(WebhookRejection::MissingSignature)?;
+
+    let event = construct_event(&ConstructEventParams {
+        payload: body,
+        signature,
+        secret: &config.secret,
+        tolerance: config.tolerance,
+    })?;
+
+    Ok(VerifiedEvent(event))
+}
+
+/// The fixtures shared by the tests of the extractors.
+#[cfg(test)]
+mod test {
+    pub(crate) use crate::webhooks::test_payload as payload;
+    use crate::webhooks::{WebhookConfig, WebhookSecret};
+
+    pub(crate) fn config() -> WebhookConfig {
+        WebhookConfig {
+            secret: WebhookSecret::from("whsec_example_123456789"),
+            tolerance: None,
+        }
+    }
+}
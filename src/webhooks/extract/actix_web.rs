@@ -0,0 +1,139 @@
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::web::{Bytes, Data};
+use actix_web::{FromRequest, HttpRequest, ResponseError};
+use futures_core::future::LocalBoxFuture;
+
+use crate::webhooks::{VerifiedEvent, WEBHOOK_SIGNATURE_HEADER, WebhookConfig, WebhookRejection};
+
+impl FromRequest for VerifiedEvent {
+    type Error = WebhookRejection;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let request = request.clone();
+        let body = Bytes::from_request(&request, payload);
+
+        Box::pin(async move {
+            let config = request
+                .app_data::<Data<WebhookConfig>>()
+                .ok_or(WebhookRejection::MissingConfig)?;
+
+            let signature = request
+                .headers()
+                .get(WEBHOOK_SIGNATURE_HEADER)
+                .and_then(|value| value.to_str().ok());
+
+            let body = body
+                .await
+                .map_err(|err| WebhookRejection::InvalidBody(err.to_string()))?;
+
+            super::verify(config, signature, &body)
+        })
+    }
+}
+
+impl ResponseError for WebhookRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status().as_u16())
+            .expect("status code of the rejection should be valid")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::rt::System;
+    use actix_web::test::TestRequest;
+    use chrono::{TimeDelta, Utc};
+
+    use crate::events::{EventData, EventId};
+    use crate::webhooks::extract::test::{config, payload};
+    use crate::webhooks::{WebhookSecret, WebhookSignature};
+
+    use super::*;
+
+    fn extract(request: TestRequest) -> Result<VerifiedEvent, WebhookRejection> {
+        let (request, mut payload) = request.set_payload(payload()).to_http_parts();
+
+        System::new().block_on(VerifiedEvent::from_request(&request, &mut payload))
+    }
+
+    #[test]
+    fn it_extracts_a_verified_event() {
+        let signature = WebhookSignature::sign(&config().secret, Utc::now(), &payload());
+
+        let VerifiedEvent(event) = extract(
+            TestRequest::post()
+                .app_data(Data::new(config()))
+                .insert_header((WEBHOOK_SIGNATURE_HEADER, signature.to_string())),
+        )
+        .unwrap();
+
+        assert_eq!(event.id, EventId::from("event_01H2GQNMQNH8VRXVR7AEYG9XCJ"));
+        assert!(matches!(event.data, EventData::OrganizationCreated(_)))
+    }
+
+    #[test]
+    fn it_rejects_a_request_without_a_signature_with_bad_request() {
+        let rejection = extract(TestRequest::post().app_data(Data::new(config()))).unwrap_err();
+
+        assert_eq!(rejection.error_response().status(), StatusCode::BAD_REQUEST)
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_signature_with_unauthorized() {
+        let signature = WebhookSignature::sign(
+            &WebhookSecret::from("whsec_example_987654321"),
+            Utc::now(),
+            &payload(),
+        );
+
+        let rejection = extract(
+            TestRequest::post()
+                .app_data(Data::new(config()))
+                .insert_header((WEBHOOK_SIGNATURE_HEADER, signature.to_string())),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            rejection.error_response().status(),
+            StatusCode::UNAUTHORIZED
+        )
+    }
+
+    #[test]
+    fn it_rejects_an_expired_signature_with_unauthorized() {
+        let signature = WebhookSignature::sign(
+            &config().secret,
+            Utc::now() - TimeDelta::minutes(5),
+            &payload(),
+        );
+
+        let rejection = extract(
+            TestRequest::post()
+                .app_data(Data::new(config()))
+                .insert_header((WEBHOOK_SIGNATURE_HEADER, signature.to_string())),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            rejection.error_response().status(),
+            StatusCode::UNAUTHORIZED
+        )
+    }
+
+    #[test]
+    fn it_fails_without_a_webhook_config() {
+        let signature = WebhookSignature::sign(&config().secret, Utc::now(), &payload());
+
+        let rejection = extract(
+            TestRequest::post().insert_header((WEBHOOK_SIGNATURE_HEADER, signature.to_string())),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            rejection.error_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        )
+    }
+}
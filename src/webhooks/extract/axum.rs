@@ -0,0 +1,128 @@
+use axum::body::Bytes;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::response::{IntoResponse, Response};
+
+use crate::webhooks::{VerifiedEvent, WEBHOOK_SIGNATURE_HEADER, WebhookConfig, WebhookRejection};
+
+impl<S> FromRequest<S> for VerifiedEvent
+where
+    WebhookConfig: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let config = WebhookConfig::from_ref(state);
+
+        let signature = request
+            .headers()
+            .get(WEBHOOK_SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+
+        let body = Bytes::from_request(request, state)
+            .await
+            .map_err(|err| WebhookRejection::InvalidBody(err.body_text()))?;
+
+        super::verify(&config, signature.as_deref(), &body)
+    }
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::body::Body;
+    use chrono::{TimeDelta, Utc};
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::events::{EventData, EventId};
+    use crate::webhooks::extract::test::{config, payload};
+    use crate::webhooks::{WebhookSecret, WebhookSignature};
+
+    use super::*;
+
+    fn request(signature: Option<WebhookSignature>, payload: Vec<u8>) -> Request {
+        let mut request = Request::builder().method("POST").uri("/webhooks");
+
+        if let Some(signature) = signature {
+            request = request.header(WEBHOOK_SIGNATURE_HEADER, signature.to_string());
+        }
+
+        request.body(Body::from(payload)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_extracts_a_verified_event() {
+        let signature = WebhookSignature::sign(&config().secret, Utc::now(), &payload());
+
+        let VerifiedEvent(event) =
+            VerifiedEvent::from_request(request(Some(signature), payload()), &config())
+                .await
+                .unwrap();
+
+        assert_eq!(event.id, EventId::from("event_01H2GQNMQNH8VRXVR7AEYG9XCJ"));
+        assert!(matches!(event.data, EventData::OrganizationCreated(_)))
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_request_without_a_signature_with_bad_request() {
+        let rejection = VerifiedEvent::from_request(request(None, payload()), &config())
+            .await
+            .unwrap_err();
+
+        assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_invalid_signature_with_unauthorized() {
+        let signature = WebhookSignature::sign(
+            &WebhookSecret::from("whsec_example_987654321"),
+            Utc::now(),
+            &payload(),
+        );
+
+        let rejection = VerifiedEvent::from_request(request(Some(signature), payload()), &config())
+            .await
+            .unwrap_err();
+
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_expired_signature_with_unauthorized() {
+        let signature = WebhookSignature::sign(
+            &config().secret,
+            Utc::now() - TimeDelta::minutes(5),
+            &payload(),
+        );
+
+        let rejection = VerifiedEvent::from_request(request(Some(signature), payload()), &config())
+            .await
+            .unwrap_err();
+
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_signed_payload_that_is_not_an_event_with_unprocessable_entity() {
+        let payload = json!({ "id": "event_01H2GQNMQNH8VRXVR7AEYG9XCJ" })
+            .to_string()
+            .into_bytes();
+        let signature = WebhookSignature::sign(&config().secret, Utc::now(), &payload);
+
+        let rejection = VerifiedEvent::from_request(request(Some(signature), payload), &config())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            rejection.into_response().status(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        )
+    }
+}
@@ -0,0 +1,3 @@
+mod construct_event;
+
+pub use construct_event::*;
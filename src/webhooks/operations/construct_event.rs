@@ -0,0 +1,234 @@
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use thiserror::Error;
+
+use crate::events::Event;
+use crate::webhooks::{ParseWebhookSignatureError, WebhookSecret, WebhookSignature};
+
+/// The default maximum age of a webhook accepted by [`construct_event`].
+pub const DEFAULT_WEBHOOK_TOLERANCE: Duration = Duration::from_secs(180);
+
+/// The parameters for [`construct_event`].
+#[derive(Debug)]
+pub struct ConstructEventParams<'a> {
+    /// The raw body of the webhook request.
+    pub payload: &'a [u8],
+
+    /// The value of the [`WorkOS-Signature`](crate::webhooks::WEBHOOK_SIGNATURE_HEADER) header of the
+    /// webhook request.
+    pub signature: &'a str,
+
+    /// The secret of the webhook endpoint.
+    pub secret: &'a WebhookSecret,
+
+    /// The maximum difference between the time the webhook was signed and the current time.
+    ///
+    /// Defaults to [`DEFAULT_WEBHOOK_TOLERANCE`].
+    pub tolerance: Option<Duration>,
+}
+
+/// An error returned from [`construct_event`].
+#[derive(Debug, Error)]
+pub enum ConstructEventError {
+    /// The signature header could not be parsed.
+    #[error(transparent)]
+    InvalidSignatureHeader(#[from] ParseWebhookSignatureError),
+
+    /// The signature does not match the payload and secret.
+    #[error("webhook signature does not match the payload")]
+    SignatureMismatch,
+
+    /// The webhook was signed longer ago than the tolerance allows.
+    #[error("webhook timestamp is outside of the tolerance")]
+    TimestampOutsideTolerance,
+
+    /// The payload is not a valid event.
+    #[error("invalid webhook payload: {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+}
+
+/// Verifies the signature of a webhook and returns the event it contains.
+///
+/// [WorkOS Docs: Webhooks Guide](https://workos.com/docs/events/data-syncing/webhooks)
+///
+/// # Examples
+///
+/// ```
+/// # use workos::webhooks::*;
+/// use workos::events::EventData;
+///
+/// # fn run(body: &[u8], signature: &str) -> Result<(), ConstructEventError> {
+/// let event = construct_event(&ConstructEventParams {
+///     payload: body,
+///     signature,
+///     secret: &WebhookSecret::from("whsec_example_123456789"),
+///     tolerance: None,
+/// })?;
+///
+/// if let EventData::UserCreated(user_created) = event.data {
+///     println!("{}", user_created.0.email);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn construct_event(params: &ConstructEventParams<'_>) -> Result<Event, ConstructEventError> {
+    let ConstructEventParams {
+        payload,
+        signature,
+        secret,
+        tolerance,
+    } = params;
+
+    let signature: WebhookSignature = signature.parse()?;
+
+    if !signature.verify(secret, payload) {
+        return Err(ConstructEventError::SignatureMismatch);
+    }
+
+    let tolerance = TimeDelta::from_std(tolerance.unwrap_or(DEFAULT_WEBHOOK_TOLERANCE))
+        .unwrap_or(TimeDelta::MAX);
+
+    if (Utc::now() - signature.timestamp).abs() > tolerance {
+        return Err(ConstructEventError::TimestampOutsideTolerance);
+    }
+
+    let event = serde_json::from_slice(payload)?;
+
+    Ok(event)
+}
+
+/// Returns the payload of an `organization.created` event, shared by the webhook tests.
+#[cfg(test)]
+pub(crate) fn test_payload() -> Vec<u8> {
+    serde_json::json!({
+        "id": "event_01H2GQNMQNH8VRXVR7AEYG9XCJ",
+        "event": "organization.created",
+        "data": {
+            "object": "organization",
+            "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "name": "Foo Corp",
+            "allow_profiles_outside_organization": false,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "domains": []
+        },
+        "created_at": "2023-06-09T18:12:01.837Z"
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::events::{EventData, EventId};
+
+    use super::test_payload as payload;
+    use super::*;
+
+    #[test]
+    fn it_constructs_a_signed_event() {
+        let secret = WebhookSecret::from("whsec_example_123456789");
+        let payload = payload();
+        let signature = WebhookSignature::sign(&secret, Utc::now(), &payload).to_string();
+
+        let event = construct_event(&ConstructEventParams {
+            payload: &payload,
+            signature: &signature,
+            secret: &secret,
+            tolerance: None,
+        })
+        .unwrap();
+
+        assert_eq!(event.id, EventId::from("event_01H2GQNMQNH8VRXVR7AEYG9XCJ"));
+        assert!(matches!(event.data, EventData::OrganizationCreated(_)))
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_payload_was_tampered_with() {
+        let secret = WebhookSecret::from("whsec_example_123456789");
+        let signature = WebhookSignature::sign(&secret, Utc::now(), &payload()).to_string();
+
+        let result = construct_event(&ConstructEventParams {
+            payload: &payload().repeat(2),
+            signature: &signature,
+            secret: &secret,
+            tolerance: None,
+        });
+
+        assert!(matches!(
+            result,
+            Err(ConstructEventError::SignatureMismatch)
+        ))
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_secret_does_not_match() {
+        let payload = payload();
+        let signature = WebhookSignature::sign(
+            &WebhookSecret::from("whsec_example_123456789"),
+            Utc::now(),
+            &payload,
+        )
+        .to_string();
+
+        let result = construct_event(&ConstructEventParams {
+            payload: &payload,
+            signature: &signature,
+            secret: &WebhookSecret::from("whsec_example_987654321"),
+            tolerance: None,
+        });
+
+        assert!(matches!(
+            result,
+            Err(ConstructEventError::SignatureMismatch)
+        ))
+    }
+
+    #[test]
+    fn it_enforces_the_timestamp_tolerance() {
+        let secret = WebhookSecret::from("whsec_example_123456789");
+        let payload = payload();
+        let signature =
+            WebhookSignature::sign(&secret, Utc::now() - TimeDelta::minutes(5), &payload)
+                .to_string();
+
+        let result = construct_event(&ConstructEventParams {
+            payload: &payload,
+            signature: &signature,
+            secret: &secret,
+            tolerance: None,
+        });
+
+        assert!(matches!(
+            result,
+            Err(ConstructEventError::TimestampOutsideTolerance)
+        ));
+
+        let result = construct_event(&ConstructEventParams {
+            payload: &payload,
+            signature: &signature,
+            secret: &secret,
+            tolerance: Some(Duration::from_secs(600)),
+        });
+
+        assert!(result.is_ok())
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_signature_header_is_invalid() {
+        let result = construct_event(&ConstructEventParams {
+            payload: &payload(),
+            signature: "v1=abc",
+            secret: &WebhookSecret::from("whsec_example_123456789"),
+            tolerance: None,
+        });
+
+        assert!(matches!(
+            result,
+            Err(ConstructEventError::InvalidSignatureHeader(
+                ParseWebhookSignatureError::MissingTimestamp
+            ))
+        ))
+    }
+}
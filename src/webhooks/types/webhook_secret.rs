@@ -0,0 +1,15 @@
+use derive_more::From;
+
+use crate::SecretString;
+
+/// The secret of a webhook endpoint, used to verify the signatures of the webhooks sent to it.
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+#[from(forward)]
+pub struct WebhookSecret(SecretString);
+
+impl WebhookSecret {
+    /// Returns the value of the webhook secret.
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+}
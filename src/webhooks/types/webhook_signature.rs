@@ -0,0 +1,204 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::webhooks::WebhookSecret;
+
+/// The name of the header containing the [`WebhookSignature`] of a webhook.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "WorkOS-Signature";
+
+/// The signature of a webhook, sent in the [`WorkOS-Signature`](WEBHOOK_SIGNATURE_HEADER) header.
+///
+/// The header is formatted as `t=<timestamp>, v1=<signature>`, where the timestamp is the number of
+/// milliseconds since the Unix epoch, and the signature is the hex-encoded HMAC-SHA256 of the
+/// timestamp and the payload, separated by a `.`, keyed with the secret of the webhook endpoint.
+///
+/// [WorkOS Docs: Webhooks Guide](https://workos.com/docs/events/data-syncing/webhooks)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookSignature {
+    /// The time at which the webhook was signed.
+    pub timestamp: DateTime<Utc>,
+
+    /// The hex-encoded HMAC-SHA256 signature.
+    pub signature: String,
+}
+
+impl WebhookSignature {
+    /// Signs the payload with the secret at the given time.
+    ///
+    /// This is mostly useful to build signed webhooks in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Utc;
+    /// use workos::webhooks::{WEBHOOK_SIGNATURE_HEADER, WebhookSecret, WebhookSignature};
+    ///
+    /// let payload = r#"{"id":"event_01H2GNQD5D7ZE06FDDS75NFPHY"}"#;
+    ///
+    /// let signature = WebhookSignature::sign(
+    ///     &WebhookSecret::from("whsec_example_123456789"),
+    ///     Utc::now(),
+    ///     payload.as_bytes(),
+    /// );
+    ///
+    /// let header = (WEBHOOK_SIGNATURE_HEADER, signature.to_string());
+    /// ```
+    pub fn sign(secret: &WebhookSecret, timestamp: DateTime<Utc>, payload: &[u8]) -> Self {
+        let signature = hex::encode(
+            mac(secret, timestamp.timestamp_millis(), payload)
+                .finalize()
+                .into_bytes(),
+        );
+
+        Self {
+            timestamp,
+            signature,
+        }
+    }
+
+    /// Returns whether the signature is valid for the payload and secret.
+    ///
+    /// The signature is compared in constant time.
+    pub fn verify(&self, secret: &WebhookSecret, payload: &[u8]) -> bool {
+        let Ok(signature) = hex::decode(&self.signature) else {
+            return false;
+        };
+
+        mac(secret, self.timestamp.timestamp_millis(), payload)
+            .verify_slice(&signature)
+            .is_ok()
+    }
+}
+
+/// Returns the MAC of the timestamp and payload, keyed with the secret.
+fn mac(secret: &WebhookSecret, timestamp: i64, payload: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.expose_secret().as_bytes())
+        .expect("HMAC should accept keys of any length");
+
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(payload);
+
+    mac
+}
+
+/// An error returned when parsing a [`WebhookSignature`] from a header value.
+#[derive(Debug, Error)]
+pub enum ParseWebhookSignatureError {
+    /// The header does not contain a timestamp.
+    #[error("missing timestamp in webhook signature")]
+    MissingTimestamp,
+
+    /// The header does not contain a signature.
+    #[error("missing signature in webhook signature")]
+    MissingSignature,
+
+    /// The timestamp is not a valid number of milliseconds since the Unix epoch.
+    #[error("invalid timestamp in webhook signature: {0}")]
+    InvalidTimestamp(String),
+}
+
+impl FromStr for WebhookSignature {
+    type Err = ParseWebhookSignatureError;
+
+    fn from_str(header: &str) -> Result<Self, Self::Err> {
+        let mut timestamp = None;
+        let mut signature = None;
+
+        for (key, value) in header
+            .split(',')
+            .filter_map(|pair| pair.trim().split_once('='))
+        {
+            match key {
+                "t" => timestamp = Some(value),
+                "v1" => signature = Some(value),
+                _ => {}
+            }
+        }
+
+        let timestamp = timestamp.ok_or(ParseWebhookSignatureError::MissingTimestamp)?;
+        let signature = signature.ok_or(ParseWebhookSignatureError::MissingSignature)?;
+
+        let timestamp = timestamp
+            .parse()
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .ok_or_else(|| ParseWebhookSignatureError::InvalidTimestamp(timestamp.to_string()))?;
+
+        Ok(Self {
+            timestamp,
+            signature: signature.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for WebhookSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "t={}, v1={}",
+            self.timestamp.timestamp_millis(),
+            self.signature
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_signature_header() {
+        let signature = WebhookSignature::from_str(
+            "t=1686334321837, v1=2c84bb2d2ae4bd1bbe1d9d01e9cd0a9fd9c4bbc7bb2cf4d6bd8f9f10e1a0d4b1",
+        )
+        .unwrap();
+
+        assert_eq!(signature.timestamp.timestamp_millis(), 1686334321837);
+        assert_eq!(
+            signature.signature,
+            "2c84bb2d2ae4bd1bbe1d9d01e9cd0a9fd9c4bbc7bb2cf4d6bd8f9f10e1a0d4b1"
+        );
+        assert_eq!(
+            signature.to_string(),
+            "t=1686334321837, v1=2c84bb2d2ae4bd1bbe1d9d01e9cd0a9fd9c4bbc7bb2cf4d6bd8f9f10e1a0d4b1"
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_signature_is_missing() {
+        assert!(matches!(
+            WebhookSignature::from_str("t=1686334321837"),
+            Err(ParseWebhookSignatureError::MissingSignature)
+        ));
+        assert!(matches!(
+            WebhookSignature::from_str("v1=abc"),
+            Err(ParseWebhookSignatureError::MissingTimestamp)
+        ));
+        assert!(matches!(
+            WebhookSignature::from_str("t=yesterday, v1=abc"),
+            Err(ParseWebhookSignatureError::InvalidTimestamp(_))
+        ))
+    }
+
+    #[test]
+    fn it_verifies_a_signature() {
+        let secret = WebhookSecret::from("whsec_example_123456789");
+        let timestamp = DateTime::from_timestamp_millis(1686334321837).unwrap();
+
+        let signature = WebhookSignature::sign(&secret, timestamp, b"{}");
+
+        assert_eq!(
+            signature.signature,
+            "656556610ed223c02f107c7e3526e0d4c532de0ce8d7f15159814624df9b7b14"
+        );
+        assert!(signature.verify(&secret, b"{}"));
+        assert!(!signature.verify(&secret, b"{\"tampered\":true}"));
+        assert!(!signature.verify(&WebhookSecret::from("whsec_other"), b"{}"));
+    }
+}
@@ -47,7 +47,8 @@ pub struct GenerateTokenResponse {
 pub enum GenerateTokenError {}
 
 /// [WorkOS Docs: Generate a Widget token](https://workos.com/docs/reference/widgets/get-token)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GenerateToken {
     /// Generate a widget token scoped to an organization and user with the specified scopes.
     ///
@@ -82,8 +83,24 @@ pub trait GenerateToken {
     ) -> WorkOsResult<GenerateTokenResponse, GenerateTokenError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GenerateToken for Widgets<'_> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "generate_token",
+            skip_all,
+            fields(
+                http.request.method = "POST",
+                url.template = "/widgets/token",
+                http.response.status_code = tracing::field::Empty,
+                workos.request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error.type = tracing::field::Empty,
+            ),
+        )
+    )]
     async fn generate_token(
         &self,
         params: &GenerateTokenParams<'_>,
@@ -94,20 +111,18 @@ impl GenerateToken for Widgets<'_> {
             .workos
             .client()
             .post(url)
-            .bearer_auth(self.workos.key())
+            .bearer_auth(self.workos.key().expose_secret())
             .json(&params)
             .send()
             .await?
-            .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<GenerateTokenResponse>()
-            .await?;
+            .handle_unauthorized_or_generic_error()?
+            .json::<GenerateTokenResponse>()?;
 
         Ok(response)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reqwest"))]
 mod test {
     use mockito::Matcher;
     use serde_json::json;
@@ -125,7 +140,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&dbg!(server.url()))
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/widgets/token")
@@ -1,6 +1,14 @@
+mod env;
+mod registry;
+
+use std::borrow::Cow;
+use std::sync::Arc;
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+use std::time::Duration;
+
+use thiserror::Error;
 use url::{ParseError, Url};
 
-use crate::ApiKey;
 use crate::directory_sync::DirectorySync;
 use crate::events::Events;
 use crate::mfa::Mfa;
@@ -8,22 +16,36 @@ use crate::organization_domains::OrganizationDomains;
 use crate::organizations::Organizations;
 use crate::portal::Portal;
 use crate::roles::Roles;
-use crate::sso::Sso;
+use crate::sso::{ClientId, Sso};
 use crate::user_management::UserManagement;
 use crate::widgets::Widgets;
+use crate::{
+    ApiKey, Client, CredentialProvider, HttpTransport, Middleware, RequestOptions, RetryPolicy,
+};
+
+pub use env::*;
+pub use registry::*;
 
 /// The WorkOS client.
 #[derive(Clone)]
 pub struct WorkOs {
     base_url: Url,
-    key: ApiKey,
-    client: reqwest::Client,
+    auth_base_url: Option<Url>,
+    key: Arc<dyn CredentialProvider>,
+    client_id: Option<ClientId>,
+    client: Client,
 }
 
 impl WorkOs {
     /// Returns a new instance of the WorkOS client using the provided API key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client cannot be built, see [`WorkOsBuilder::build`].
     pub fn new(key: &ApiKey) -> Self {
-        WorkOsBuilder::new(key).build()
+        WorkOsBuilder::new(key)
+            .build()
+            .expect("the WorkOS client should build with the default configuration")
     }
 
     /// Returns a [`WorkOsBuilder`] that may be used to construct a WorkOS client.
@@ -31,15 +53,81 @@ impl WorkOs {
         WorkOsBuilder::new(key)
     }
 
+    /// Returns a copy of the client that applies the given [`RequestOptions`] to every request.
+    ///
+    /// The copy shares the connection pool, middleware and retry policy of this client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use workos::WorkOsResult;
+    /// # use workos::sso::ClientId;
+    /// # use workos::user_management::*;
+    /// use workos::{ApiKey, RequestOptions, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), AuthenticateError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let AuthenticationResponse { user, .. } = workos
+    ///     .with_options(&RequestOptions {
+    ///         timeout: Some(Duration::from_secs(5)),
+    ///         ..Default::default()
+    ///     })
+    ///     .user_management()
+    ///     .authenticate_with_password(&AuthenticateWithPasswordParams {
+    ///         client_id: Some(&ClientId::from("client_123456789")),
+    ///         email: "marcelina@example.com",
+    ///         password: "i8uv6g34kd490s",
+    ///         invitation_token: None,
+    ///         ip_address: None,
+    ///         user_agent: None,
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(&self, options: &RequestOptions<'_>) -> Self {
+        WorkOs {
+            base_url: self.base_url.clone(),
+            auth_base_url: self.auth_base_url.clone(),
+            key: match options.api_key {
+                Some(key) => Arc::new(key.clone()),
+                None => self.key.clone(),
+            },
+            client_id: self.client_id.clone(),
+            client: self.client.with_options(options.timeout, options.headers),
+        }
+    }
+
     pub(crate) fn base_url(&self) -> &Url {
         &self.base_url
     }
 
-    pub(crate) fn key(&self) -> &ApiKey {
-        &self.key
+    /// Returns the base URL of user-facing URLs, falling back to the base URL of the WorkOS API.
+    pub(crate) fn auth_base_url(&self) -> &Url {
+        self.auth_base_url.as_ref().unwrap_or(&self.base_url)
+    }
+
+    /// Returns the API key to authenticate the next request with.
+    pub(crate) fn key(&self) -> ApiKey {
+        self.key.api_key()
     }
 
-    pub(crate) fn client(&self) -> &reqwest::Client {
+    /// Returns the default client ID of the client, if set.
+    pub fn client_id(&self) -> Option<&ClientId> {
+        self.client_id.as_ref()
+    }
+
+    /// Returns the given client ID, falling back to the default client ID of the client.
+    pub(crate) fn client_id_or_default<'a>(
+        &'a self,
+        client_id: Option<&'a ClientId>,
+    ) -> Option<&'a ClientId> {
+        client_id.or(self.client_id.as_ref())
+    }
+
+    pub(crate) fn client(&self) -> &Client {
         &self.client
     }
 
@@ -94,18 +182,56 @@ impl WorkOs {
     }
 }
 
+/// An error returned from [`WorkOsBuilder::build`].
+#[derive(Debug, Error)]
+pub enum WorkOsBuildError {
+    /// The HTTP client could not be built from the provided configuration.
+    #[cfg(feature = "reqwest")]
+    #[error("failed to build the HTTP client")]
+    HttpClient(#[from] reqwest::Error),
+
+    /// No transport was set while the `reqwest` feature is disabled.
+    #[cfg(not(feature = "reqwest"))]
+    #[error("a transport is required when the `reqwest` feature is disabled")]
+    MissingTransport,
+}
+
 /// A builder for a WorkOS client.
 pub struct WorkOsBuilder<'a> {
     base_url: Url,
-    key: &'a ApiKey,
+    auth_base_url: Option<Url>,
+    key: Cow<'a, ApiKey>,
+    credential_provider: Option<Arc<dyn CredentialProvider>>,
+    client_id: Option<ClientId>,
+    retry_policy: RetryPolicy,
+    transport: Option<Arc<dyn HttpTransport>>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    #[cfg(feature = "reqwest")]
+    http_client: Option<reqwest::Client>,
+    #[cfg(feature = "reqwest")]
+    http_client_builder: reqwest::ClientBuilder,
 }
 
 impl<'a> WorkOsBuilder<'a> {
     /// Returns a new [`WorkOsBuilder`] using the provided API key.
     pub fn new(key: &'a ApiKey) -> Self {
+        Self::with_key(Cow::Borrowed(key))
+    }
+
+    fn with_key(key: Cow<'a, ApiKey>) -> Self {
         Self {
             base_url: Url::parse("https://api.workos.com").unwrap(),
+            auth_base_url: None,
             key,
+            credential_provider: None,
+            client_id: None,
+            retry_policy: RetryPolicy::none(),
+            transport: None,
+            middleware: Vec::new(),
+            #[cfg(feature = "reqwest")]
+            http_client: None,
+            #[cfg(feature = "reqwest")]
+            http_client_builder: reqwest::Client::builder(),
         }
     }
 
@@ -115,37 +241,234 @@ impl<'a> WorkOsBuilder<'a> {
         Ok(self)
     }
 
+    /// Sets the base URL of user-facing URLs, such as authorization, logout and JWKS URLs.
+    ///
+    /// Use this to point these URLs to an AuthKit custom domain (e.g. `https://auth.your-app.com`)
+    /// while API requests are still sent to the base URL. Defaults to the base URL.
+    pub fn auth_base_url(mut self, auth_base_url: &'a str) -> Result<Self, ParseError> {
+        self.auth_base_url = Some(Url::parse(auth_base_url)?);
+        Ok(self)
+    }
+
     /// Sets the API key that the client will use.
+    ///
+    /// This replaces a [`CredentialProvider`] set with [`WorkOsBuilder::credential_provider`].
     pub fn key(mut self, key: &'a ApiKey) -> Self {
-        self.key = key;
+        self.key = Cow::Borrowed(key);
+        self.credential_provider = None;
         self
     }
 
-    /// Consumes the builder and returns the constructed client.
-    pub fn build(self) -> WorkOs {
-        let client = reqwest::Client::builder()
-            .user_agent(concat!("workos-rust/", env!("CARGO_PKG_VERSION")))
-            .build()
-            .unwrap();
+    /// Sets the [`CredentialProvider`] that the client asks for the API key of every request.
+    ///
+    /// This replaces the API key of the builder, and allows the key to be rotated at runtime,
+    /// e.g. with a [`SharedApiKey`](crate::SharedApiKey).
+    pub fn credential_provider<P: CredentialProvider + 'static>(mut self, provider: P) -> Self {
+        self.credential_provider = Some(Arc::new(provider));
+        self
+    }
 
-        WorkOs {
+    /// Sets the default client ID of the client.
+    ///
+    /// Operations that take a client ID fall back to it when none is provided in their parameters.
+    pub fn client_id(mut self, client_id: &ClientId) -> Self {
+        self.client_id = Some(client_id.to_owned());
+        self
+    }
+
+    /// Sets the policy used to retry failed requests.
+    ///
+    /// Requests are not retried by default.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the HTTP transport used to send requests.
+    ///
+    /// Defaults to a [`ReqwestTransport`](crate::ReqwestTransport) when the `reqwest` feature is enabled.
+    /// The HTTP client options of this builder are ignored when a transport is provided.
+    pub fn transport<T: HttpTransport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets a pre-built [`reqwest::Client`] to send requests with.
+    ///
+    /// The HTTP client options of this builder are ignored when a client is provided.
+    #[cfg(feature = "reqwest")]
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets the timeout for connecting to the WorkOS API.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.connect_timeout(timeout);
+        self
+    }
+
+    /// Sets the timeout for each read of a response from the WorkOS API.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.read_timeout(timeout);
+        self
+    }
+
+    /// Sets the total timeout of a request, from connecting until the response body has been read.
+    ///
+    /// The timeout applies to each attempt when requests are retried.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.timeout(timeout);
+        self
+    }
+
+    /// Adds a proxy to send requests through.
+    ///
+    /// Proxies are read from the environment (e.g. `HTTPS_PROXY`) by default.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http_client_builder = self.http_client_builder.proxy(proxy);
+        self
+    }
+
+    /// Disables all proxies, including those read from the environment.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn no_proxy(mut self) -> Self {
+        self.http_client_builder = self.http_client_builder.no_proxy();
+        self
+    }
+
+    /// Adds a custom root certificate to trust when connecting to the WorkOS API.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.http_client_builder = self.http_client_builder.add_root_certificate(certificate);
+        self
+    }
+
+    /// Only uses HTTP/1 to send requests.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn http1_only(mut self) -> Self {
+        self.http_client_builder = self.http_client_builder.http1_only();
+        self
+    }
+
+    /// Only uses HTTP/2 to send requests, without negotiating the protocol first.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http_client_builder = self.http_client_builder.http2_prior_knowledge();
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept in the pool per host.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http_client_builder = self.http_client_builder.pool_max_idle_per_host(max);
+        self
+    }
+
+    /// Sets the duration after which idle connections are closed, or [`None`] to keep them open.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.http_client_builder = self.http_client_builder.pool_idle_timeout(timeout);
+        self
+    }
+
+    /// Adds a middleware to the end of the middleware chain.
+    ///
+    /// Middleware is invoked for every request in the order in which it was added.
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Consumes the builder and returns the constructed client.
+    ///
+    /// Returns an error if the HTTP client cannot be built from the provided configuration,
+    /// or if no transport was set while the `reqwest` feature is disabled.
+    pub fn build(self) -> Result<WorkOs, WorkOsBuildError> {
+        let transport = match self.transport {
+            Some(transport) => transport,
+            #[cfg(feature = "reqwest")]
+            None => {
+                let http_client = match self.http_client {
+                    Some(http_client) => http_client,
+                    None => self.http_client_builder.build()?,
+                };
+
+                Arc::new(crate::ReqwestTransport::new(http_client))
+            }
+            #[cfg(not(feature = "reqwest"))]
+            None => return Err(WorkOsBuildError::MissingTransport),
+        };
+
+        Ok(WorkOs {
             base_url: self.base_url,
-            key: self.key.to_owned(),
-            client,
-        }
+            auth_base_url: self.auth_base_url,
+            key: match self.credential_provider {
+                Some(credential_provider) => credential_provider,
+                None => Arc::new(self.key.into_owned()),
+            },
+            client_id: self.client_id,
+            client: Client::new(transport, self.middleware, self.retry_policy),
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+
+    #[cfg(feature = "reqwest")]
+    use crate::RequestError;
+    use crate::organizations::{GetOrganization, OrganizationId};
+    use crate::{HttpRequest, HttpResponse, TransportError};
+
     use super::*;
 
+    #[derive(Default)]
+    struct FakeTransport {
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl HttpTransport for FakeTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+            self.requests.lock().unwrap().push(request);
+
+            Ok(HttpResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: json!({
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "object": "organization",
+                    "name": "Foo Corporation",
+                    "allow_profiles_outside_organization": false,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z",
+                    "domains": []
+                })
+                .to_string()
+                .into_bytes(),
+            })
+        }
+    }
+
     #[test]
     fn it_supports_setting_the_base_url_through_the_builder() {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url("https://auth.your-app.com")
             .unwrap()
-            .build();
+            .transport(Arc::new(FakeTransport::default()))
+            .build()
+            .unwrap();
 
         assert_eq!(
             workos.base_url(),
@@ -157,11 +480,156 @@ mod test {
     fn it_supports_setting_the_api_key_through_the_builder() {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .key(&ApiKey::from("sk_another_api_key"))
-            .build();
+            .transport(Arc::new(FakeTransport::default()))
+            .build()
+            .unwrap();
+
+        assert_eq!(workos.key(), ApiKey::from("sk_another_api_key"))
+    }
+
+    #[test]
+    fn it_supports_setting_the_retry_policy_through_the_builder() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .retry_policy(RetryPolicy::default())
+            .transport(Arc::new(FakeTransport::default()))
+            .build()
+            .unwrap();
+
+        assert_eq!(workos.client().retry_policy(), &RetryPolicy::default())
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn it_sends_requests_through_an_injected_http_client() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("X-Custom-Header", "custom".parse().unwrap());
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .http_client(
+                reqwest::Client::builder()
+                    .default_headers(headers)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/health")
+            .match_header("X-Custom-Header", "custom")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let url = workos.base_url().join("/health").unwrap();
+        workos.client().get(url).send().await.unwrap();
+
+        mock.assert_async().await
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn it_times_out_requests_through_the_builder() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&base_url)
+            .unwrap()
+            .connect_timeout(Duration::from_secs(1))
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/health").unwrap();
+        let result = workos.client().get(url).send().await;
+
+        assert!(matches!(
+            result,
+            Err(RequestError::Transport(TransportError::Timeout(_)))
+        ))
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn it_applies_request_options_to_the_requests() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .match_header("Authorization", "Bearer sk_another_api_key")
+            .match_header("X-Custom-Header", "custom")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "object": "organization",
+                    "name": "Foo Corporation",
+                    "allow_profiles_outside_organization": false,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z",
+                    "domains": []
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Custom-Header", "custom".parse().unwrap());
+
+        workos
+            .with_options(&RequestOptions {
+                headers: Some(&headers),
+                api_key: Some(&ApiKey::from("sk_another_api_key")),
+                ..Default::default()
+            })
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(workos.key(), ApiKey::from("sk_example_123456789"))
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn it_overrides_the_timeout_through_request_options() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&base_url)
+            .unwrap()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .unwrap()
+            .with_options(&RequestOptions {
+                timeout: Some(Duration::from_millis(100)),
+                ..Default::default()
+            });
+
+        let url = workos.base_url().join("/health").unwrap();
+        let result = workos.client().get(url).send().await;
 
-        assert_eq!(workos.key(), &ApiKey::from("sk_another_api_key"))
+        assert!(matches!(
+            result,
+            Err(RequestError::Transport(TransportError::Timeout(_)))
+        ))
     }
 
+    #[cfg(feature = "reqwest")]
     #[tokio::test]
     async fn it_sets_the_user_agent_header_on_the_client() {
         let mut server = mockito::Server::new_async().await;
@@ -169,7 +637,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/health")
@@ -184,8 +653,49 @@ mod test {
 
         let url = workos.base_url().join("/health").unwrap();
         let response = workos.client().get(url).send().await.unwrap();
-        let response_body = response.text().await.unwrap();
+        let response_body = response.text();
 
         assert_eq!(response_body, "User-Agent correctly set")
     }
+
+    #[tokio::test]
+    async fn it_sends_requests_through_a_custom_transport() {
+        let transport = Arc::new(FakeTransport::default());
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let organization = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            organization.id,
+            OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
+        );
+
+        let requests = transport.requests.lock().unwrap();
+
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://api.workos.com/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT"
+        );
+        assert_eq!(
+            requests[0].headers.get("Authorization").unwrap(),
+            "Bearer sk_example_123456789"
+        )
+    }
+
+    #[cfg(not(feature = "reqwest"))]
+    #[test]
+    fn it_requires_a_transport_without_the_reqwest_feature() {
+        let result = WorkOs::builder(&ApiKey::from("sk_example_123456789")).build();
+
+        assert!(matches!(result, Err(WorkOsBuildError::MissingTransport)))
+    }
 }
@@ -0,0 +1,284 @@
+use std::borrow::Cow;
+use std::env::{self, VarError};
+use std::error::Error;
+use std::str::FromStr;
+use std::time::Duration;
+
+use thiserror::Error;
+use url::Url;
+
+use crate::sso::ClientId;
+use crate::{ApiKey, RetryPolicy, WorkOs, WorkOsBuildError, WorkOsBuilder};
+
+const API_KEY: &str = "WORKOS_API_KEY";
+const CLIENT_ID: &str = "WORKOS_CLIENT_ID";
+const BASE_URL: &str = "WORKOS_BASE_URL";
+const API_HOSTNAME: &str = "WORKOS_API_HOSTNAME";
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+const TIMEOUT_MS: &str = "WORKOS_TIMEOUT_MS";
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+const CONNECT_TIMEOUT_MS: &str = "WORKOS_CONNECT_TIMEOUT_MS";
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+const READ_TIMEOUT_MS: &str = "WORKOS_READ_TIMEOUT_MS";
+const RETRY_MAX_ATTEMPTS: &str = "WORKOS_RETRY_MAX_ATTEMPTS";
+const RETRY_BASE_DELAY_MS: &str = "WORKOS_RETRY_BASE_DELAY_MS";
+const RETRY_MAX_DELAY_MS: &str = "WORKOS_RETRY_MAX_DELAY_MS";
+
+/// An error returned when configuring a WorkOS client from environment variables.
+#[derive(Debug, Error)]
+pub enum FromEnvError {
+    /// A required environment variable is not set.
+    #[error("missing environment variable `{0}`")]
+    MissingVar(&'static str),
+
+    /// An environment variable has a malformed value.
+    #[error("invalid environment variable `{name}`")]
+    InvalidVar {
+        /// The name of the environment variable.
+        name: &'static str,
+
+        /// The error that occurred while parsing the value.
+        #[source]
+        source: Box<dyn Error + Send + Sync>,
+    },
+
+    /// The client could not be built.
+    #[error(transparent)]
+    Build(#[from] WorkOsBuildError),
+}
+
+impl WorkOs {
+    /// Returns a new instance of the WorkOS client configured from environment variables.
+    ///
+    /// See [`WorkOsBuilder::from_env`] for the supported environment variables.
+    pub fn from_env() -> Result<Self, FromEnvError> {
+        Ok(WorkOsBuilder::from_env()?.build()?)
+    }
+}
+
+impl WorkOsBuilder<'static> {
+    /// Returns a new [`WorkOsBuilder`] configured from environment variables.
+    ///
+    /// The following environment variables are supported:
+    ///
+    /// - `WORKOS_API_KEY` (required): the API key.
+    /// - `WORKOS_CLIENT_ID`: the default client ID.
+    /// - `WORKOS_BASE_URL`: the base URL of the WorkOS API, e.g. `https://api.workos.com`.
+    /// - `WORKOS_API_HOSTNAME`: the hostname of the WorkOS API, used over HTTPS when `WORKOS_BASE_URL` is not set.
+    /// - `WORKOS_TIMEOUT_MS`, `WORKOS_CONNECT_TIMEOUT_MS` and `WORKOS_READ_TIMEOUT_MS`: the timeouts in milliseconds,
+    ///   when the `reqwest` feature is enabled.
+    /// - `WORKOS_RETRY_MAX_ATTEMPTS`, `WORKOS_RETRY_BASE_DELAY_MS` and `WORKOS_RETRY_MAX_DELAY_MS`: the retry policy,
+    ///   starting from [`RetryPolicy::default`] when any of them is set.
+    ///
+    /// Variables that are set to an empty value are treated as not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use workos::{FromEnvError, WorkOsBuilder};
+    ///
+    /// # fn run() -> Result<(), FromEnvError> {
+    /// let workos = WorkOsBuilder::from_env()?.build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self, FromEnvError> {
+        Self::from_lookup(env::var)
+    }
+
+    fn from_lookup<F>(lookup: F) -> Result<Self, FromEnvError>
+    where
+        F: Fn(&'static str) -> Result<String, VarError>,
+    {
+        let vars = EnvVars { lookup };
+
+        let key = vars
+            .get(API_KEY)?
+            .ok_or(FromEnvError::MissingVar(API_KEY))?;
+        let mut builder = WorkOsBuilder::with_key(Cow::Owned(ApiKey::from(key)));
+
+        if let Some(client_id) = vars.get(CLIENT_ID)? {
+            builder = builder.client_id(&ClientId::from(client_id));
+        }
+
+        if let Some(base_url) = vars.parse::<Url>(BASE_URL)? {
+            builder.base_url = base_url;
+        } else if let Some(hostname) = vars.get(API_HOSTNAME)? {
+            builder.base_url = Url::parse(&format!("https://{hostname}"))
+                .map_err(|err| FromEnvError::invalid(API_HOSTNAME, err))?;
+        }
+
+        #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+        {
+            if let Some(timeout) = vars.parse_millis(TIMEOUT_MS)? {
+                builder = builder.timeout(timeout);
+            }
+
+            if let Some(timeout) = vars.parse_millis(CONNECT_TIMEOUT_MS)? {
+                builder = builder.connect_timeout(timeout);
+            }
+
+            if let Some(timeout) = vars.parse_millis(READ_TIMEOUT_MS)? {
+                builder = builder.read_timeout(timeout);
+            }
+        }
+
+        let max_attempts = vars.parse::<u32>(RETRY_MAX_ATTEMPTS)?;
+        let base_delay = vars.parse_millis(RETRY_BASE_DELAY_MS)?;
+        let max_delay = vars.parse_millis(RETRY_MAX_DELAY_MS)?;
+
+        if max_attempts.is_some() || base_delay.is_some() || max_delay.is_some() {
+            let default = RetryPolicy::default();
+
+            builder = builder.retry_policy(RetryPolicy {
+                max_attempts: max_attempts.unwrap_or(default.max_attempts),
+                base_delay: base_delay.unwrap_or(default.base_delay),
+                max_delay: max_delay.unwrap_or(default.max_delay),
+                ..default
+            });
+        }
+
+        Ok(builder)
+    }
+}
+
+impl FromEnvError {
+    fn invalid<E>(name: &'static str, source: E) -> Self
+    where
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        FromEnvError::InvalidVar {
+            name,
+            source: source.into(),
+        }
+    }
+}
+
+struct EnvVars<F> {
+    lookup: F,
+}
+
+impl<F> EnvVars<F>
+where
+    F: Fn(&'static str) -> Result<String, VarError>,
+{
+    fn get(&self, name: &'static str) -> Result<Option<String>, FromEnvError> {
+        match (self.lookup)(name) {
+            Ok(value) if value.trim().is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value.trim().to_string())),
+            Err(VarError::NotPresent) => Ok(None),
+            Err(err @ VarError::NotUnicode(_)) => Err(FromEnvError::invalid(name, err)),
+        }
+    }
+
+    fn parse<T>(&self, name: &'static str) -> Result<Option<T>, FromEnvError>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn Error + Send + Sync>>,
+    {
+        self.get(name)?
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|err| FromEnvError::invalid(name, err))
+            })
+            .transpose()
+    }
+
+    fn parse_millis(&self, name: &'static str) -> Result<Option<Duration>, FromEnvError> {
+        Ok(self.parse::<u64>(name)?.map(Duration::from_millis))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use matches::assert_matches;
+
+    use super::*;
+
+    fn builder_from(vars: &[(&'static str, &str)]) -> Result<WorkOsBuilder<'static>, FromEnvError> {
+        let vars = HashMap::<_, _>::from_iter(vars.iter().copied());
+
+        WorkOsBuilder::from_lookup(|name| {
+            vars.get(name)
+                .map(|value| value.to_string())
+                .ok_or(VarError::NotPresent)
+        })
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn it_reads_the_configuration_from_the_environment() {
+        let workos = builder_from(&[
+            (API_KEY, "sk_example_123456789"),
+            (CLIENT_ID, "client_123456789"),
+            (BASE_URL, "https://auth.your-app.com"),
+            (TIMEOUT_MS, "5000"),
+            (RETRY_MAX_ATTEMPTS, "5"),
+        ])
+        .unwrap()
+        .build()
+        .unwrap();
+
+        assert_eq!(workos.key(), ApiKey::from("sk_example_123456789"));
+        assert_eq!(
+            workos.client_id(),
+            Some(&ClientId::from("client_123456789"))
+        );
+        assert_eq!(
+            workos.base_url(),
+            &Url::parse("https://auth.your-app.com").unwrap()
+        );
+        assert_eq!(
+            workos.client().retry_policy(),
+            &RetryPolicy {
+                max_attempts: 5,
+                ..Default::default()
+            }
+        )
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn it_builds_the_base_url_from_the_api_hostname() {
+        let workos = builder_from(&[
+            (API_KEY, "sk_example_123456789"),
+            (API_HOSTNAME, "api.workos.test"),
+        ])
+        .unwrap()
+        .build()
+        .unwrap();
+
+        assert_eq!(
+            workos.base_url(),
+            &Url::parse("https://api.workos.test").unwrap()
+        );
+        assert_eq!(workos.client().retry_policy(), &RetryPolicy::none())
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_api_key_is_missing() {
+        let result = builder_from(&[(API_KEY, ""), (CLIENT_ID, "client_123456789")]).map(|_| ());
+
+        assert_matches!(result, Err(FromEnvError::MissingVar("WORKOS_API_KEY")))
+    }
+
+    #[test]
+    fn it_returns_an_error_when_a_value_is_malformed() {
+        let result = builder_from(&[
+            (API_KEY, "sk_example_123456789"),
+            (RETRY_BASE_DELAY_MS, "1s"),
+        ])
+        .map(|_| ());
+
+        assert_matches!(
+            result,
+            Err(FromEnvError::InvalidVar {
+                name: "WORKOS_RETRY_BASE_DELAY_MS",
+                ..
+            })
+        )
+    }
+}
@@ -0,0 +1,361 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use url::Url;
+
+use crate::sso::ClientId;
+use crate::{ApiKey, CredentialProvider, WorkOs};
+
+/// The source of the API key of a WorkOS environment in a [`WorkOsRegistry`].
+#[derive(Clone)]
+pub enum EnvironmentCredentials<'a> {
+    /// A fixed API key.
+    ApiKey(&'a ApiKey),
+
+    /// A [`CredentialProvider`] that the client of the environment asks for the API key of every
+    /// request, which allows the key to be rotated at runtime, e.g. with a
+    /// [`SharedApiKey`](crate::SharedApiKey).
+    Provider(Arc<dyn CredentialProvider>),
+}
+
+impl<'a> From<&'a ApiKey> for EnvironmentCredentials<'a> {
+    fn from(api_key: &'a ApiKey) -> Self {
+        Self::ApiKey(api_key)
+    }
+}
+
+impl fmt::Debug for EnvironmentCredentials<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApiKey(api_key) => f.debug_tuple("ApiKey").field(api_key).finish(),
+            Self::Provider(_) => f.debug_tuple("Provider").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// The configuration of a WorkOS environment in a [`WorkOsRegistry`].
+#[derive(Clone, Debug)]
+pub struct EnvironmentOptions<'a> {
+    /// The source of the API key of the environment.
+    pub credentials: EnvironmentCredentials<'a>,
+
+    /// The default client ID of the environment.
+    ///
+    /// Defaults to the client ID of the client the registry was created from.
+    pub client_id: Option<&'a ClientId>,
+
+    /// The base URL of the WorkOS API for the environment.
+    ///
+    /// Defaults to the base URL of the client the registry was created from.
+    pub base_url: Option<&'a Url>,
+
+    /// The base URL of user-facing URLs for the environment, such as an AuthKit custom domain.
+    ///
+    /// Defaults to the base URL of user-facing URLs of the client the registry was created from.
+    pub auth_base_url: Option<&'a Url>,
+}
+
+/// A set of WorkOS clients for several environments, keyed by environment.
+///
+/// Every client in the registry shares the connection pool, middleware and retry policy of the
+/// client the registry was created from, and only differs in its API key, client ID and base URLs.
+///
+/// # Examples
+///
+/// ```
+/// # use workos::WorkOsResult;
+/// # use workos::user_management::*;
+/// use workos::{ApiKey, EnvironmentCredentials, EnvironmentOptions, WorkOs, WorkOsRegistry};
+///
+/// # async fn run() -> WorkOsResult<(), GetUserError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+///
+/// let mut registry = WorkOsRegistry::new(&workos);
+///
+/// registry.insert(
+///     "staging",
+///     &EnvironmentOptions {
+///         credentials: EnvironmentCredentials::ApiKey(&ApiKey::from("sk_test_123456789")),
+///         client_id: None,
+///         base_url: None,
+///         auth_base_url: None,
+///     },
+/// );
+/// registry.insert(
+///     "production",
+///     &EnvironmentOptions {
+///         credentials: EnvironmentCredentials::ApiKey(&ApiKey::from("sk_live_123456789")),
+///         client_id: None,
+///         base_url: None,
+///         auth_base_url: None,
+///     },
+/// );
+///
+/// if let Some(workos) = registry.get("staging") {
+///     let user = workos
+///         .user_management()
+///         .get_user(&UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"))
+///         .await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WorkOsRegistry<K> {
+    workos: WorkOs,
+    clients: HashMap<K, WorkOs>,
+}
+
+impl<K: Eq + Hash> WorkOsRegistry<K> {
+    /// Returns a new, empty registry whose clients share the connection pool of the provided client.
+    pub fn new(workos: &WorkOs) -> Self {
+        Self {
+            workos: workos.clone(),
+            clients: HashMap::new(),
+        }
+    }
+
+    /// Adds a client for the environment, returning the client it replaces, if any.
+    pub fn insert(&mut self, environment: K, options: &EnvironmentOptions<'_>) -> Option<WorkOs> {
+        let mut workos = self.workos.clone();
+
+        workos.key = match &options.credentials {
+            EnvironmentCredentials::ApiKey(api_key) => Arc::new((*api_key).clone()),
+            EnvironmentCredentials::Provider(provider) => provider.clone(),
+        };
+
+        if let Some(client_id) = options.client_id {
+            workos.client_id = Some(client_id.clone());
+        }
+
+        if let Some(base_url) = options.base_url {
+            workos.base_url = base_url.clone();
+        }
+
+        if let Some(auth_base_url) = options.auth_base_url {
+            workos.auth_base_url = Some(auth_base_url.clone());
+        }
+
+        self.clients.insert(environment, workos)
+    }
+
+    /// Returns the client for the environment, if any.
+    pub fn get<Q>(&self, environment: &Q) -> Option<&WorkOs>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.clients.get(environment)
+    }
+
+    /// Removes the client for the environment, returning it if it was present.
+    pub fn remove<Q>(&mut self, environment: &Q) -> Option<WorkOs>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.clients.remove(environment)
+    }
+
+    /// Returns an iterator over the environments in the registry, in arbitrary order.
+    pub fn environments(&self) -> impl Iterator<Item = &K> {
+        self.clients.keys()
+    }
+}
+
+impl<K: fmt::Debug> fmt::Debug for WorkOsRegistry<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkOsRegistry")
+            .field("environments", &self.clients.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use http::{HeaderMap, StatusCode};
+    use serde_json::json;
+
+    use crate::organizations::{GetOrganization, OrganizationId};
+    use crate::{HttpRequest, HttpResponse, HttpTransport, SharedApiKey, TransportError};
+
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    enum Environment {
+        Staging,
+        Production,
+    }
+
+    #[derive(Default)]
+    struct FakeTransport {
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl HttpTransport for FakeTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+            self.requests.lock().unwrap().push(request);
+
+            Ok(HttpResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: json!({
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "object": "organization",
+                    "name": "Foo Corporation",
+                    "allow_profiles_outside_organization": false,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z",
+                    "domains": []
+                })
+                .to_string()
+                .into_bytes(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn it_routes_requests_to_the_environment_through_a_shared_transport() {
+        let transport = Arc::new(FakeTransport::default());
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let mut registry = WorkOsRegistry::new(&workos);
+
+        registry.insert(
+            Environment::Staging,
+            &EnvironmentOptions {
+                credentials: EnvironmentCredentials::ApiKey(&ApiKey::from("sk_test_123456789")),
+                client_id: Some(&ClientId::from("client_staging")),
+                base_url: Some(&Url::parse("https://staging.workos.test").unwrap()),
+                auth_base_url: None,
+            },
+        );
+        registry.insert(
+            Environment::Production,
+            &EnvironmentOptions {
+                credentials: EnvironmentCredentials::ApiKey(&ApiKey::from("sk_live_123456789")),
+                client_id: Some(&ClientId::from("client_production")),
+                base_url: None,
+                auth_base_url: None,
+            },
+        );
+
+        for environment in [Environment::Staging, Environment::Production] {
+            registry
+                .get(&environment)
+                .unwrap()
+                .organizations()
+                .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+                .await
+                .unwrap();
+        }
+
+        let requests = transport.requests.lock().unwrap();
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://staging.workos.test/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT"
+        );
+        assert_eq!(
+            requests[0].headers.get("Authorization").unwrap(),
+            "Bearer sk_test_123456789"
+        );
+        assert_eq!(
+            requests[1].url.as_str(),
+            "https://api.workos.com/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT"
+        );
+        assert_eq!(
+            requests[1].headers.get("Authorization").unwrap(),
+            "Bearer sk_live_123456789"
+        );
+        assert_eq!(
+            registry.get(&Environment::Production).unwrap().client_id(),
+            Some(&ClientId::from("client_production"))
+        )
+    }
+
+    #[tokio::test]
+    async fn it_uses_the_credential_provider_of_the_environment() {
+        let transport = Arc::new(FakeTransport::default());
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let key = SharedApiKey::new(&ApiKey::from("sk_test_123456789"));
+
+        let mut registry = WorkOsRegistry::new(&workos);
+
+        registry.insert(
+            Environment::Staging,
+            &EnvironmentOptions {
+                credentials: EnvironmentCredentials::Provider(Arc::new(key.clone())),
+                client_id: None,
+                base_url: None,
+                auth_base_url: None,
+            },
+        );
+
+        let staging = registry.get(&Environment::Staging).unwrap();
+
+        for rotated_key in ["sk_test_123456789", "sk_test_987654321"] {
+            key.set(&ApiKey::from(rotated_key));
+
+            staging
+                .organizations()
+                .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+                .await
+                .unwrap();
+        }
+
+        let requests = transport.requests.lock().unwrap();
+
+        assert_eq!(
+            requests[0].headers.get("Authorization").unwrap(),
+            "Bearer sk_test_123456789"
+        );
+        assert_eq!(
+            requests[1].headers.get("Authorization").unwrap(),
+            "Bearer sk_test_987654321"
+        )
+    }
+
+    #[test]
+    fn it_returns_none_for_an_unknown_environment() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .transport(Arc::new(FakeTransport::default()))
+            .build()
+            .unwrap();
+
+        let mut registry = WorkOsRegistry::new(&workos);
+
+        registry.insert(
+            "staging".to_string(),
+            &EnvironmentOptions {
+                credentials: EnvironmentCredentials::ApiKey(&ApiKey::from("sk_test_123456789")),
+                client_id: None,
+                base_url: None,
+                auth_base_url: None,
+            },
+        );
+
+        assert!(registry.get("staging").is_some());
+        assert!(registry.get("production").is_none());
+        assert!(registry.remove("staging").is_some());
+        assert_eq!(registry.environments().count(), 0)
+    }
+}
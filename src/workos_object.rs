@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+
+use crate::directory_sync::{Directory, DirectoryGroup, DirectoryUser};
+use crate::events::Event;
+use crate::mfa::{AuthenticationChallenge, AuthenticationFactor};
+use crate::organization_domains::OrganizationDomain;
+use crate::organizations::Organization;
+use crate::roles::Role;
+use crate::sso::{Connection, Profile};
+use crate::user_management::{
+    EmailVerification, Invitation, MagicAuth, OrganizationMembership, PasswordReset, Session, User,
+};
+
+/// Any object returned by the WorkOS API, discriminated by its `object` field.
+///
+/// This allows payloads that mix several kinds of objects, such as exports or fixtures, to be
+/// deserialized without knowing their contents ahead of time.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use workos::WorkOsObject;
+///
+/// let object: WorkOsObject = serde_json::from_value(json!({
+///     "object": "organization",
+///     "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+///     "name": "Foo Corp",
+///     "allow_profiles_outside_organization": false,
+///     "created_at": "2021-06-25T19:07:33.155Z",
+///     "updated_at": "2021-06-25T19:07:33.155Z",
+///     "domains": []
+/// }))
+/// .unwrap();
+///
+/// if let WorkOsObject::Organization(organization) = object {
+///     println!("{}", organization.name);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum WorkOsObject {
+    /// An [`AuthenticationChallenge`].
+    AuthenticationChallenge(AuthenticationChallenge),
+
+    /// An [`AuthenticationFactor`].
+    AuthenticationFactor(AuthenticationFactor),
+
+    /// A [`Connection`].
+    Connection(Connection),
+
+    /// A [`Directory`].
+    Directory(Directory),
+
+    /// A [`DirectoryGroup`].
+    DirectoryGroup(DirectoryGroup),
+
+    /// A [`DirectoryUser`].
+    DirectoryUser(DirectoryUser),
+
+    /// An [`EmailVerification`].
+    EmailVerification(EmailVerification),
+
+    /// An [`Event`].
+    Event(Box<Event>),
+
+    /// An [`Invitation`].
+    Invitation(Invitation),
+
+    /// A [`MagicAuth`].
+    MagicAuth(MagicAuth),
+
+    /// An [`Organization`].
+    Organization(Organization),
+
+    /// An [`OrganizationDomain`].
+    OrganizationDomain(OrganizationDomain),
+
+    /// An [`OrganizationMembership`].
+    OrganizationMembership(OrganizationMembership),
+
+    /// A [`PasswordReset`].
+    PasswordReset(PasswordReset),
+
+    /// A [`Profile`].
+    Profile(Profile),
+
+    /// A [`Role`].
+    Role(Role),
+
+    /// A [`Session`].
+    Session(Session),
+
+    /// A [`User`].
+    User(User),
+
+    /// An object that is not known to this version of the SDK, or that could not be deserialized
+    /// into the type of its `object` field.
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::organizations::OrganizationId;
+    use crate::user_management::UserId;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_objects_by_their_object_field() {
+        let objects: Vec<WorkOsObject> = serde_json::from_value(json!([
+            {
+                "object": "user",
+                "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                "email": "marcelina.davis@example.com",
+                "first_name": "Marcelina",
+                "last_name": "Davis",
+                "email_verified": true,
+                "profile_picture_url": null,
+                "metadata": {},
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z"
+            },
+            {
+                "object": "organization",
+                "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                "name": "Foo Corp",
+                "allow_profiles_outside_organization": false,
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z",
+                "domains": []
+            }
+        ]))
+        .unwrap();
+
+        let mut objects = objects.into_iter();
+
+        assert!(matches!(
+            objects.next(),
+            Some(WorkOsObject::User(user)) if user.id == UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
+        ));
+        assert!(matches!(
+            objects.next(),
+            Some(WorkOsObject::Organization(organization))
+                if organization.id == OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
+        ))
+    }
+
+    #[test]
+    fn it_deserializes_unknown_objects() {
+        let value = json!({
+            "object": "feature_flag",
+            "id": "flag_01EHZNVPK3SFK441A1RGBFSHRT",
+            "enabled": true
+        });
+
+        let object: WorkOsObject = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(object, WorkOsObject::Unknown(value.clone()));
+        assert_eq!(serde_json::to_value(&object).unwrap(), value)
+    }
+
+    #[test]
+    fn it_serializes_the_object_field() {
+        let value = json!({
+            "object": "organization",
+            "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "name": "Foo Corp",
+            "allow_profiles_outside_organization": false,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "domains": []
+        });
+
+        let object: WorkOsObject = serde_json::from_value(value).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&object).unwrap()["object"],
+            json!("organization")
+        )
+    }
+}